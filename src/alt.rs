@@ -0,0 +1,287 @@
+/// Dispatch to the first parser whose predicate accepts the first token tree of
+/// the input.
+/// <sup>**[tt-call]**</sup>
+///
+/// This is a generalization of [`tt_if!`] from two fixed branches of tokens to
+/// any number of tt-call parsers. The predicate of each branch is invoked in
+/// order on the first token tree of the input, and the parser of the first
+/// branch whose predicate returns true is invoked on the entire input. The
+/// outputs of that parser become the outputs of `tt_alt!`.
+///
+/// [`tt_if!`]: macro.tt_if.html
+///
+/// # Input
+///
+///   - **`$(`**<br>
+///     &emsp;&emsp;`branch = [{` name of predicate macro `=>` name of parser
+///     macro `}]`<br>
+///     &emsp;&emsp;or `branch = [{` name of predicate macro `(` inputs to pass
+///     to the predicate `) =>` name of parser macro `}]`<br>
+///     **`)*`**
+///   - `otherwise = [{` name of parser macro to invoke if no predicate returns
+///     true `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macros must accept an input value named `input`, after any
+/// inputs given in parentheses in their branch, and return a single output
+/// value holding the tokens `true` or `false`, just like the predicate of
+/// `tt_if!`. The parser macros must accept a single input value named `input`.
+///
+/// If no predicate returns true and there is no `otherwise` parser, the first
+/// token of the input is reported as unexpected.
+///
+/// # Output
+///
+///   - whatever is returned by the selected parser
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{parse_type, tt_alt, tt_call, tt_is_lifetime, tt_return};
+///
+/// macro_rules! parse_lifetime {
+///     {
+///         $caller:tt
+///         input = [{ $lifetime:lifetime $($rest:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             lifetime = [{ $lifetime }]
+///             rest = [{ $($rest)* }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! describe_bound {
+///     ($($bound:tt)*) => {
+///         tt_call! {
+///             macro = [{ tt_alt }]
+///             branch = [{ tt_is_lifetime => parse_lifetime }]
+///             otherwise = [{ parse_type }]
+///             input = [{ $($bound)* }]
+///             ~~> describe_bound_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! describe_bound_return {
+///     {
+///         lifetime = [{ $lifetime:lifetime }]
+///         rest = [{ }]
+///     } => {
+///         concat!("lifetime ", stringify!($lifetime))
+///     };
+///
+///     {
+///         type = [{ $($ty:tt)* }]
+///         rest = [{ }]
+///     } => {
+///         concat!("type ", stringify!($($ty)*))
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(describe_bound!('static), "lifetime 'static");
+///     assert_eq!(describe_bound!(Send), "type Send");
+/// }
+/// ```
+///
+/// Inputs in parentheses after a predicate are passed to it along with the
+/// token. Here [`tt_eq!`] picks out a leading `mut` without a predicate written
+/// just for it. Like any call that uses `tt_eq!`, this one must be in item or
+/// statement position, so it is wrapped in a block.
+///
+/// [`tt_eq!`]: macro.tt_eq.html
+///
+/// ```
+/// use tt_call::{tt_alt, tt_call, tt_eq, tt_return};
+///
+/// macro_rules! parse_mut {
+///     {
+///         $caller:tt
+///         input = [{ mut $($rest:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             mutable = [{ true }]
+///             rest = [{ $($rest)* }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! parse_not_mut {
+///     {
+///         $caller:tt
+///         input = [{ $($rest:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             mutable = [{ false }]
+///             rest = [{ $($rest)* }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! is_mut {
+///     ($($binding:tt)*) => {{
+///         tt_call! {
+///             macro = [{ tt_alt }]
+///             branch = [{ tt_eq(expected = [{ mut }]) => parse_mut }]
+///             otherwise = [{ parse_not_mut }]
+///             input = [{ $($binding)* }]
+///             ~~> is_mut_return
+///         }
+///     }};
+/// }
+///
+/// macro_rules! is_mut_return {
+///     {
+///         mutable = [{ $mutable:tt }]
+///         rest = [{ $($rest:tt)* }]
+///     } => {
+///         $mutable
+///     };
+/// }
+///
+/// fn main() {
+///     assert!(is_mut!(mut x));
+///     assert!(!is_mut!(x));
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_alt {
+    {
+        $caller:tt
+        $(
+            branch = [{ $($branch:tt)* }]
+        )*
+        otherwise = [{ $($otherwise:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_alt! {
+            $caller
+            branches = [{ $( [ $($branch)* ] )* }]
+            otherwise = [{ $($otherwise)::* }]
+            input = [{ $($input)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        $(
+            branch = [{ $($branch:tt)* }]
+        )*
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_alt! {
+            $caller
+            branches = [{ $( [ $($branch)* ] )* }]
+            otherwise = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_alt {
+    // Input is empty so there is no token for the predicates to look at.
+    // Invoke the fallback parser.
+    {
+        $caller:tt
+        branches = [{ $($branches:tt)* }]
+        otherwise = [{ $($otherwise:ident)::+ }]
+        input = [{ }]
+    } => {
+        $($otherwise)::* ! {
+            $caller
+            input = [{ }]
+        }
+    };
+
+    // Unexpected: input is empty and there is no fallback parser.
+    {
+        $caller:tt
+        branches = [{ $($branches:tt)* }]
+        otherwise = [{ }]
+        input = [{ }]
+    } => {
+        $crate::error_eof! {}
+    };
+
+    // Branch without inputs for the predicate. Give it an empty list of them.
+    {
+        $caller:tt
+        branches = [{
+            [ $($condition:ident)::* => $($parser:ident)::* ]
+            $($branches:tt)*
+        }]
+        otherwise = [{ $($otherwise:ident)::* }]
+        input = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::private_alt! {
+            $caller
+            branches = [{
+                [ $($condition)::* () => $($parser)::* ]
+                $($branches)*
+            }]
+            otherwise = [{ $($otherwise)::* }]
+            input = [{ $first $($rest)* }]
+        }
+    };
+
+    // Invoke the predicate of the next branch on the first token tree.
+    {
+        $caller:tt
+        branches = [{
+            [ $($condition:ident)::* ( $($condition_args:tt)* ) => $($parser:ident)::* ]
+            $($branches:tt)*
+        }]
+        otherwise = [{ $($otherwise:ident)::* }]
+        input = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::private_if! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            input = [{ $first }]
+            true = [{
+                $($parser)::* ! {
+                    input = [{ $first $($rest)* }]
+                }
+            }]
+            false = [{
+                $crate::private_alt! {
+                    branches = [{ $($branches)* }]
+                    otherwise = [{ $($otherwise)::* }]
+                    input = [{ $first $($rest)* }]
+                }
+            }]
+        }
+    };
+
+    // No predicate returned true. Invoke the fallback parser.
+    {
+        $caller:tt
+        branches = [{ }]
+        otherwise = [{ $($otherwise:ident)::+ }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $($otherwise)::* ! {
+            $caller
+            input = [{ $($input)* }]
+        }
+    };
+
+    // Unexpected: no predicate returned true and there is no fallback parser.
+    {
+        $caller:tt
+        branches = [{ }]
+        otherwise = [{ }]
+        input = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::error_unexpected! {
+            $first
+        }
+    };
+}
@@ -0,0 +1,183 @@
+/// Evaluate a tt-call macro once per block of inputs and return all of the
+/// outputs to a single return destination.
+///
+/// # Input
+///
+/// Like [`tt_call!`], the input starts with the name of the macro to call.
+///
+/// [`tt_call!`]: macro.tt_call.html
+///
+///   - `macro = [{` name of macro to call `}]`
+///
+/// Then the inputs of each call, as a list of blocks.
+///
+///   - `each = [{` zero or more blocks of the form `[{` key-value inputs `}]`
+///     `}]`
+///
+/// After that there may be any number of key-value pairs to be passed to every
+/// call, before the inputs from the block.
+///
+///   - **`$(`**<br>
+///     &emsp;&emsp;arbitrary key `= [{` arbitrary tokens `}]`<br>
+///     **`)*`**
+///
+/// Finally the return destination, in any of the forms accepted by
+/// `tt_call!`.
+///
+///   - `~~>` return destination
+///
+/// # Output
+///
+///   - **`$(`**<br>
+///     &emsp;&emsp;`result = [{` outputs of one call `}]`<br>
+///     **`)*`**
+///
+/// There is one `result` per block, in the order of the blocks.
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{parse_type, tt_call_each, tt_debug};
+///
+/// fn main() {
+///     tt_call_each! {
+///         macro = [{ parse_type }]
+///         each = [{
+///             [{ input = [{ Vec<u8> }] }]
+///             [{ input = [{ &'static str }] }]
+///         }]
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// result = [{ type = [{ Vec<u8> }] rest = [{ }] }]
+/// result = [{ type = [{ &'static str }] rest = [{ }] }]
+/// ```
+///
+/// Here the same condition and replacement are passed to every call of
+/// [`tt_replace!`], and the return destination gathers the results into an
+/// array.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// ```
+/// use tt_call::{tt_call_each, tt_replace, tt_return};
+///
+/// macro_rules! is_lowercase_self {
+///     {
+///         $caller:tt
+///         input = [{ self }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ true }]
+///         }
+///     };
+///
+///     {
+///         $caller:tt
+///         input = [{ $other:tt }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ false }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! array {
+///     {
+///         $(
+///             result = [{ tokens = [{ $($expr:tt)* }] }]
+///         )*
+///     } => {
+///         [ $( $($expr)* ),* ]
+///     };
+/// }
+///
+/// fn main() {
+///     let value = 5;
+///     let array = tt_call_each! {
+///         macro = [{ tt_replace }]
+///         each = [{
+///             [{ input = [{ self + 1 }] }]
+///             [{ input = [{ self * 2 }] }]
+///         }]
+///         condition = [{ is_lowercase_self }]
+///         replace_with = [{ value }]
+///         ~~> array
+///     };
+///     assert_eq!(array, [6, 10]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_call_each {
+    {
+        macro = [{ $($m:ident)::* }]
+        each = [{ $( [{ $($block:tt)* }] )* }]
+        $(
+            $arg:ident = [{ $($value:tt)* }]
+        )*
+        ~~> $($return:tt)+
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::tt_map }]
+            map = [{ $crate::private_call_each }]
+            args = [{
+                call = [{ $($m)::* }]
+                args = [{
+                    $(
+                        $arg = [{ $($value)* }]
+                    )*
+                }]
+            }]
+            input = [{ $( [{ $($block)* }] )* }]
+            ~~> $crate::private_call_each_return! {
+                return = [{ $($return)+ }]
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_call_each {
+    // Call the macro with the shared inputs followed by the inputs from one
+    // block. The macro returns directly to the caller of this one.
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        args = [{ $($args:tt)* }]
+        input = [{ $($block:tt)* }]
+    } => {
+        $($m)::* ! {
+            $caller
+            $($args)*
+            $($block)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_call_each_return {
+    // Pass one `result` per call to the return destination.
+    {
+        return = [{ $($return:tt)* }]
+        mapped = [{ $( [{ $($output:tt)* }] )* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_return_values }]
+            values = [{
+                $(
+                    result = [{ $($output)* }]
+                )*
+            }]
+            ~~> $($return)*
+        }
+    };
+}
@@ -0,0 +1,235 @@
+/// Keep only the token trees that match a given predicate.
+/// <sup>**[tt-call]**</sup>
+///
+/// The predicate is applied to each top-level token tree of the input. Groups
+/// are kept or dropped as a whole without looking inside of them.
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+/// For example the built-in `tt_is_ident!` predicate expands to
+/// `is_ident = [{ true }]` or `is_ident = [{ false }]`.
+///
+/// # Output
+///
+///   - `tokens = [{` token trees for which the predicate is true `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_debug, tt_filter, tt_is_ident};
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ tt_filter }]
+///         condition = [{ tt_is_ident }]
+///         input = [{ "a" b 'c' d }]
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// tokens = [{ b d }]
+/// ```
+#[macro_export]
+macro_rules! tt_filter {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::tt_partition }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            input = [{ $($input)* }]
+            ~~> $crate::private_filter! {
+                $caller
+            }
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_filter! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_filter {
+    // Return from partitioning. Discard the tokens for which the predicate is
+    // false.
+    {
+        $caller:tt
+        true = [{ $($true:tt)* }]
+        false = [{ $($false:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($true)* }]
+        }
+    };
+}
+
+/// Separate the token trees that match a given predicate from the ones that do
+/// not.
+/// <sup>**[tt-call]**</sup>
+///
+/// The predicate is applied to each top-level token tree of the input. Groups
+/// are placed on one side or the other as a whole without looking inside of
+/// them. Both sides keep the original order of their token trees.
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+///
+/// # Output
+///
+///   - `true = [{` token trees for which the predicate is true `}]`
+///   - `false = [{` token trees for which the predicate is false `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_is_lifetime, tt_partition};
+///
+/// macro_rules! count_generics {
+///     ($($param:tt),*) => {
+///         tt_call! {
+///             macro = [{ tt_partition }]
+///             condition = [{ tt_is_lifetime }]
+///             input = [{ $($param)* }]
+///             ~~> count_generics_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! count_generics_return {
+///     {
+///         true = [{ $($lifetime:lifetime)* }]
+///         false = [{ $($ty:ident)* }]
+///     } => {
+///         (
+///             [$(stringify!($lifetime)),*].len(),
+///             [$(stringify!($ty)),*].len(),
+///         )
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(count_generics!('a, T, 'b, U, V), (2, 3));
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_partition {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_partition! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            true = [{ }]
+            false = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_partition! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_partition {
+    // Arrived at end of input. Return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        true = [{ $($true:tt)* }]
+        false = [{ $($false:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            true = [{ $($true)* }]
+            false = [{ $($false)* }]
+        }
+    };
+
+    // Invoke condition on the next token tree and continue.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        true = [{ $($true:tt)* }]
+        false = [{ $($false:tt)* }]
+        rest = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::private_if! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            input = [{ $first }]
+            true = [{
+                $crate::private_partition! {
+                    condition = [{ $($condition)::* }]
+                    condition_args = [{ $($condition_args)* }]
+                    true = [{ $($true)* $first }]
+                    false = [{ $($false)* }]
+                    rest = [{ $($rest)* }]
+                }
+            }]
+            false = [{
+                $crate::private_partition! {
+                    condition = [{ $($condition)::* }]
+                    condition_args = [{ $($condition_args)* }]
+                    true = [{ $($true)* }]
+                    false = [{ $($false)* $first }]
+                    rest = [{ $($rest)* }]
+                }
+            }]
+        }
+    };
+}
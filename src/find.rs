@@ -0,0 +1,391 @@
+/// Locate the first token tree that matches a given predicate.
+/// <sup>**[tt-call]**</sup>
+///
+/// The predicate is applied to each top-level token tree of the input in order
+/// until it first returns true. Groups are tested as a whole without looking
+/// inside of them; see [`tt_find_path!`] for a search that descends into
+/// groups.
+///
+/// [`tt_find_path!`]: macro.tt_find_path.html
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+///
+/// # Output
+///
+///   - `before = [{` token trees before the match, or the entire input if
+///     nothing matches `}]`
+///   - `found = [{` the matching token tree, or nothing if nothing matches `}]`
+///   - `after = [{` token trees after the match `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_debug, tt_find, tt_return};
+///
+/// macro_rules! is_fat_arrow {
+///     {
+///         $caller:tt
+///         input = [{ => }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is_fat_arrow = [{ true }]
+///         }
+///     };
+///
+///     {
+///         $caller:tt
+///         input = [{ $other:tt }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is_fat_arrow = [{ false }]
+///         }
+///     };
+/// }
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ tt_find }]
+///         condition = [{ is_fat_arrow }]
+///         input = [{ Some(x) if x > 0 => x * 2 }]
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// before = [{ Some (x) if x > 0 }]
+/// found = [{ => }]
+/// after = [{ x * 2 }]
+/// ```
+#[macro_export]
+macro_rules! tt_find {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::tt_take_until }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            input = [{ $($input)* }]
+            ~~> $crate::private_find! {
+                $caller
+            }
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_find! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_find {
+    // Found a match. Return to caller.
+    {
+        $caller:tt
+        prefix = [{ $($before:tt)* }]
+        rest = [{ $found:tt $($after:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            before = [{ $($before)* }]
+            found = [{ $found }]
+            after = [{ $($after)* }]
+        }
+    };
+
+    // Nothing matches. Return to caller.
+    {
+        $caller:tt
+        prefix = [{ $($before:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            before = [{ $($before)* }]
+            found = [{ }]
+            after = [{ }]
+        }
+    };
+}
+
+/// Locate the first token that matches a given predicate, descending into
+/// groups, and return the path of indices leading to it.
+/// <sup>**[tt-call]**</sup>
+///
+/// Token trees are visited in order. Parenthesized, square bracketed and curly
+/// braced groups are not tested by the predicate but searched recursively, in
+/// the same way that [`tt_replace!`] handles them.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+///
+/// # Output
+///
+///   - `path = [{` one index per level of nesting `}]`
+///   - `found = [{` the matching token, or nothing if nothing matches `}]`
+///
+/// The first index in the path is the position of the outermost token tree
+/// containing the match within the input, and the last index is the position
+/// of the match within its innermost group. If nothing matches, the path is
+/// empty.
+///
+/// Each index is written in unary as a square bracketed group holding one `1`
+/// token per token tree that precedes it: `[]` is the first token tree, `[1 1]`
+/// is the third. A macro that receives an index as `[$($one:tt)*]` can turn it
+/// into an integer expression with `0 $(+ $one)*`.
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_find_path, tt_is_lifetime};
+///
+/// macro_rules! lifetime_path {
+///     ($($input:tt)*) => {
+///         tt_call! {
+///             macro = [{ tt_find_path }]
+///             condition = [{ tt_is_lifetime }]
+///             input = [{ $($input)* }]
+///             ~~> lifetime_path_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! lifetime_path_return {
+///     {
+///         path = [{ $( [$($one:tt)*] )* }]
+///         found = [{ $($found:tt)* }]
+///     } => {
+///         [ $( 0 $(+ $one)* ),* ]
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(lifetime_path!(a b (c [d 'x] e) 'y), [2, 1, 1]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_find_path {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_find_path! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            index = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_find_path! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_find_path {
+    // Arrived at end of input without a match. Return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            path = [{ }]
+            found = [{ }]
+        }
+    };
+
+    // Next token tree is a parenthesized group. Recurse to search contents.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        rest = [{ ( $($group:tt)* ) $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_find_path }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            index = [{ }]
+            rest = [{ $($group)* }]
+            ~~> $crate::private_find_path! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                index = [{ $($index)* }]
+                after_group = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Next token tree is a square bracketed group. Recurse to search contents.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        rest = [{ [ $($group:tt)* ] $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_find_path }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            index = [{ }]
+            rest = [{ $($group)* }]
+            ~~> $crate::private_find_path! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                index = [{ $($index)* }]
+                after_group = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Next token tree is a curly braced group. Recurse to search contents.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        rest = [{ { $($group:tt)* } $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_find_path }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            index = [{ }]
+            rest = [{ $($group)* }]
+            ~~> $crate::private_find_path! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                index = [{ $($index)* }]
+                after_group = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Return from searching a group that does not contain a match. Continue
+    // after the group.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        after_group = [{ $($rest:tt)* }]
+        path = [{ }]
+        found = [{ }]
+    } => {
+        $crate::private_find_path! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            index = [{ $($index)* 1 }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Return from searching a group that contains a match. Prepend the index
+    // of the group and return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        after_group = [{ $($rest:tt)* }]
+        path = [{ $($path:tt)* }]
+        found = [{ $found:tt }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            path = [{ [$($index)*] $($path)* }]
+            found = [{ $found }]
+        }
+    };
+
+    // Next token is not a group, invoke condition and continue.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        index = [{ $($index:tt)* }]
+        rest = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::private_if! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            input = [{ $first }]
+            true = [{
+                $crate::tt_return! {
+                    path = [{ [$($index)*] }]
+                    found = [{ $first }]
+                }
+            }]
+            false = [{
+                $crate::private_find_path! {
+                    condition = [{ $($condition)::* }]
+                    condition_args = [{ $($condition_args)* }]
+                    index = [{ $($index)* 1 }]
+                    rest = [{ $($rest)* }]
+                }
+            }]
+        }
+    };
+}
@@ -0,0 +1,123 @@
+/// Combine the elements of a list from left to right into an accumulated value.
+/// <sup>**[tt-call]**</sup>
+///
+/// The step macro is invoked once per element with the accumulator so far and
+/// the tokens of the element, and returns the accumulator to use for the next
+/// element. The final accumulator is returned.
+///
+/// # Input
+///
+///   - `step = [{` name of step macro `}]`
+///   - `initial = [{` initial accumulator tokens `}]`
+///   - `input = [{` zero or more elements of the form `[{` arbitrary tokens
+///     `}]` `}]`
+///
+/// The step macro must accept input values named `acc` and `element`, in that
+/// order. It is expected to return a single output value which may have any
+/// name and holds the new accumulator.
+///
+/// # Output
+///
+///   - `acc = [{` accumulator after the last element `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_fold, tt_return};
+///
+/// macro_rules! pair {
+///     {
+///         $caller:tt
+///         acc = [{ $($acc:tt)* }]
+///         element = [{ $($element:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             acc = [{ ($($acc)*, $($element)*) }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! nested_pairs {
+///     ($([$($ty:tt)*])*) => {
+///         tt_call! {
+///             macro = [{ tt_fold }]
+///             step = [{ pair }]
+///             initial = [{ () }]
+///             input = [{ $( [{ $($ty)* }] )* }]
+///         }
+///     };
+/// }
+///
+/// fn main() {
+///     let nested: nested_pairs!([u8] [&str] [bool]) = ((((), 1), "two"), true);
+///     assert_eq!((nested.0).1, "two");
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_fold {
+    {
+        $caller:tt
+        step = [{ $($step:ident)::* }]
+        initial = [{ $($initial:tt)* }]
+        input = [{ $( [{ $($element:tt)* }] )* }]
+    } => {
+        $crate::private_fold! {
+            $caller
+            step = [{ $($step)::* }]
+            acc = [{ $($initial)* }]
+            rest = [{ $( [{ $($element)* }] )* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_fold {
+    // Arrived at end of input. Return to caller.
+    {
+        $caller:tt
+        step = [{ $($step:ident)::* }]
+        acc = [{ $($acc:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            acc = [{ $($acc)* }]
+        }
+    };
+
+    // Invoke the step macro on the next element.
+    {
+        $caller:tt
+        step = [{ $($step:ident)::* }]
+        acc = [{ $($acc:tt)* }]
+        rest = [{ [{ $($element:tt)* }] $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($step)::* }]
+            acc = [{ $($acc)* }]
+            element = [{ $($element)* }]
+            ~~> $crate::private_fold! {
+                $caller
+                step = [{ $($step)::* }]
+                after_element = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Return from step macro with the new accumulator.
+    {
+        $caller:tt
+        step = [{ $($step:ident)::* }]
+        after_element = [{ $($rest:tt)* }]
+        $acc:ident = [{ $($new:tt)* }]
+    } => {
+        $crate::private_fold! {
+            $caller
+            step = [{ $($step)::* }]
+            acc = [{ $($new)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+}
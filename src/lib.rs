@@ -37,72 +37,81 @@
 //!
 //! - **Macros that conform to tt-call must be invoked with curly braces.**
 //!
-//!     ```
-//!     # macro_rules! some_macro {
-//!     #     () => {};
-//!     # }
-//!     #
-//!     some_macro! {
-//!         /* ... */
-//!     }
-//!     ```
+//!   ```
+//!   # macro_rules! some_macro {
+//!   #     () => {};
+//!   # }
+//!   #
+//!   some_macro! {
+//!       /* ... */
+//!   }
+//!   ```
 //!
-//!     The Rust grammar is very particular about punctuation after
-//!     parenthesized and square bracketed macro invocations. In expression or
-//!     type position they must not be followed by a semicolon. In item or
-//!     statement position they are required to be followed by a semicolon. The
-//!     inconsistency is applied transitively to any helper macros they forward
-//!     to, and means that parenthesized and square bracketed macro invocations
-//!     must decide whether to support expression and type position only or item
-//!     and statement position only. They cannot support both, which is a
-//!     problem for broadly applicable macro building blocks.
+//!   The Rust grammar is very particular about punctuation after
+//!   parenthesized and square bracketed macro invocations. In expression or
+//!   type position they must not be followed by a semicolon. In item or
+//!   statement position they are required to be followed by a semicolon. The
+//!   inconsistency is applied transitively to any helper macros they forward
+//!   to, and means that parenthesized and square bracketed macro invocations
+//!   must decide whether to support expression and type position only or item
+//!   and statement position only. They cannot support both, which is a
+//!   problem for broadly applicable macro building blocks.
 //!
-//!     There is no such punctuation requirement after curly brace invocations.
-//!     Consistently using curly braces makes the same macro building blocks
-//!     usable in any syntactic position.
+//!   There is no such punctuation requirement after curly brace invocations.
+//!   Consistently using curly braces makes the same macro building blocks
+//!   usable in any syntactic position.
 //!
 //! - **Input and output values must be passed in the following key-value
 //!   form.**
 //!
-//!     ```
-//!     # macro_rules! some_macro {
-//!     #     {
-//!     $key:ident = [{ $($value:tt)* }]
-//!     #     } => {};
-//!     # }
-//!     ```
+//!   ```
+//!   # macro_rules! some_macro {
+//!   #     {
+//!   $key:ident = [{ $($value:tt)* }]
+//!   #     } => {};
+//!   # }
+//!   ```
 //!
-//!     This is enforced by the `tt_call!` and `tt_return!` macros. The
-//!     consistency is important for composability and makes it possible to
-//!     write higher-order macros that operate on the input or output of an
-//!     arbitrary tt-call macro.
+//!   This is enforced by the `tt_call!` and `tt_return!` macros. The
+//!   consistency is important for composability and makes it possible to
+//!   write higher-order macros that operate on the input or output of an
+//!   arbitrary tt-call macro.
 //!
-//!     Except in libraries intended specifically as tt-call building blocks,
-//!     generally tt-call macros will be private `#[doc(hidden)]` helpers with a
-//!     user-facing non-tt-call entry point. Thus the rigid key-value syntax
-//!     need not be exposed to users of the public macro.
+//!   Inputs may be optional, in which case the macro falls back to a
+//!   default value when the caller leaves them out. Use [`tt_signature!`] to
+//!   declare the inputs of a macro along with their defaults, which lets
+//!   callers give them in any order and lets the macro gain new optional
+//!   inputs later without breaking existing callers.
+//!
+//!   [`tt_signature!`]: macro.tt_signature.html
+//!
+//!   Except in libraries intended specifically as tt-call building blocks,
+//!   generally tt-call macros will be private `#[doc(hidden)]` helpers with a
+//!   user-facing non-tt-call entry point. Thus the rigid key-value syntax
+//!   need not be exposed to users of the public macro.
 //!
 //! - **Before its key-value inputs, every rule must accept a `$caller:tt`.**
 //!
-//!     This is an opaque tt bundle used by `tt_call!` and `tt_return!` to
-//!     record the call hierarchy. A `tt_return!` accepts a `$caller` to return
-//!     back to.
+//!   This is an opaque tt bundle used by `tt_call!` and `tt_return!` to
+//!   record the call hierarchy. A `tt_return!` accepts a `$caller` to return
+//!   back to.
 //!
 //! - **Every rule must expand to exactly one macro invocation and nothing
 //!   else.**
 //!
-//!     Output tokens are expected to be returned through `tt_return!`.
-//!     Expanding to nothing, expanding to more than one macro invocation, or
-//!     expanding to anything other than a macro invocation are not permitted.
+//!   Output tokens are expected to be returned through `tt_return!`.
+//!   Expanding to nothing, expanding to more than one macro invocation, or
+//!   expanding to anything other than a macro invocation are not permitted.
 //!
 //!
 //! ## Examples
 //!
-//! Just as a flavor of the syntax, one of the rules from the implementation of
-//! the built-in [`tt_replace!`] macro is written as follows. The macro takes in
-//! a token stream and for each token that matches a given predicate it replaces
-//! that token with a given replacement sequence of tokens. For example the
-//! caller may want to replace the token `self` with the single token `__value`.
+//! Just as a flavor of the syntax, a simplified form of one of the rules from
+//! the implementation of the built-in [`tt_replace!`] macro is written as
+//! follows. The macro takes in a token stream and for each token that matches
+//! a given predicate it replaces that token with a given replacement sequence
+//! of tokens. For example the caller may want to replace the token `self` with
+//! the single token `__value`.
 //!
 //! The rule shown here is responsible for performing one step of the
 //! replacement. It matches one token of input in `$first:tt`, uses [`tt_if!`]
@@ -149,7 +158,7 @@
 //! # }
 //! ```
 //!
-//! Here is another macro rule selected from `tt_replace!`. This one matches if
+//! Here is another macro rule simplified from `tt_replace!`. This one matches if
 //! the tt-muncher has reached the end of its input. It returns the finished
 //! tokens back to the caller using `tt_return!`.
 //!
@@ -233,6 +242,54 @@
 //! ```
 //!
 //!
+//! ## Position
+//!
+//! A macro\_rules pattern can only match tokens that are written in the
+//! pattern itself. Macros that need to match tokens chosen by the caller, such
+//! as [`tt_eq!`] comparing against an expected token, do so by turning the
+//! caller's tokens into the rules of a helper `macro_rules!` macro and invoking
+//! it. Such a call expands to a `macro_rules!` item followed by an invocation
+//! of that macro, which the compiler accepts only in item or statement
+//! position. Nothing else in the call hierarchy changes this: any tt-call macro
+//! that invokes one of these, like a combinator given `tt_eq!` as its
+//! condition, must likewise be called in item or statement position.
+//!
+//! The macros that define a helper macro are:
+//!
+//! - [`tt_eq!`]
+//! - [`tt_replace_seq!`]
+//! - [`tt_rename!`]
+//! - [`tt_template!`]
+//! - [`tt_call!`] when given `expect`, `rename`, or `select` with names
+//!
+//! [`tt_eq!`]: macro.tt_eq.html
+//! [`tt_replace_seq!`]: macro.tt_replace_seq.html
+//! [`tt_rename!`]: macro.tt_rename.html
+//! [`tt_template!`]: macro.tt_template.html
+//!
+//! To use the result as an expression, wrap the call in a block so that the
+//! call is in statement position within it. There is no such workaround for a
+//! call in type position.
+//!
+//! ```
+//! use tt_call::{tt_call, tt_eq};
+//!
+//! macro_rules! same_token {
+//!     ($expected:tt $input:tt) => {{
+//!         tt_call! {
+//!             macro = [{ tt_eq }]
+//!             expected = [{ $expected }]
+//!             input = [{ $input }]
+//!         }
+//!     }};
+//! }
+//!
+//! fn main() {
+//!     assert!(same_token!(=> =>));
+//! }
+//! ```
+//!
+//!
 //! ## Motivation
 //!
 //! This may seem like a lot of ceremony around what should be very simple macro
@@ -345,9 +402,22 @@
 #![doc(html_root_url = "https://docs.rs/tt-call/1.0.9")]
 #![allow(clippy::module_name_repetitions, clippy::needless_doctest_main)]
 
+mod alt;
+mod each;
+mod filter;
+mod find;
+mod fold;
+mod map;
 mod predicate;
+mod rename;
 mod replace;
 mod rust;
+mod sequence;
+mod signature;
+mod split;
+mod take;
+mod template;
+mod unary;
 mod unexpected;
 
 // In general it is not possible today in Rust to produce good error messages
@@ -369,8 +439,19 @@ mod unexpected;
 ///
 ///   - `macro = [{` name of macro to call `}]`
 ///
+/// It may be followed by an optional recursion budget which limits how many
+/// levels of macro expansion the call may spend on further calls, see below.
+///
+///   - `budget(` integer literal from 0 to 999 `)` &emsp;&emsp;(optional)
+///
+/// Next may come the names of the values that the macro is expected to return,
+/// in order, see below.
+///
+///   - `expect = [{` names of return values `}]` &emsp;&emsp;(optional)
+///
 /// After that there may be any number of key-value pairs to be passed as
-/// arguments to the macro being called.
+/// arguments to the macro being called. An argument named `expect` right after
+/// the macro name is only passed on if its value is not a list of identifiers.
 ///
 ///   - **`$(`**<br>
 ///     &emsp;&emsp;arbitrary key `= [{` arbitrary tokens `}]`<br>
@@ -383,6 +464,22 @@ mod unexpected;
 ///     &emsp;&emsp;arbitrary tokens<br>
 ///     `}`
 ///
+/// The return destination may be followed by a table of names to give the
+/// return values before they are passed on, or replaced by the name of a
+/// single return value to expand to.
+///
+///   - `~~>` return destination `rename(`<br>
+///     &emsp;&emsp;comma separated entries of the form `from => to`<br>
+///     `)`
+///   - `~~> select(` names of return values `)`
+///   - `~~> select[` position of one return value, counting from 1 `]`
+///   - `~~> select[..]` &emsp;&emsp;(all return values)
+///
+/// More than one `~~>` makes a pipeline. Every destination other than the last
+/// must be a tt-call macro, and it is called with the return values of the
+/// previous macro as its inputs, after any arguments written in its own
+/// `! { ... }`.
+///
 /// # Examples
 ///
 /// ```
@@ -459,8 +556,375 @@ mod unexpected;
 ///     println!("{}", is_ident); // prints true or false
 /// }
 /// ```
+///
+/// If the invoked macro produces more than one output value, as the parsers in
+/// this crate do, give the position of the one to expand to with
+/// `~~> select[...]`, counting from 1, or expand to the tokens of all of them
+/// in order with `~~> select[..]`. Like the call without a destination, these
+/// work in expression and type position.
+///
+/// ```
+/// use tt_call::{parse_type, tt_call, tt_is_comma, tt_is_ident, tt_take_until};
+///
+/// fn main() {
+///     let sum: tt_call! {
+///         macro = [{ parse_type }]
+///         input = [{ i32 }]
+///         ~~> select[1]
+///     } = tt_call! {
+///         macro = [{ tt_take_until }]
+///         condition = [{ tt_is_comma }]
+///         input = [{ 1 + 2, 3 + 4 }]
+///         ~~> select[1]
+///     };
+///     assert_eq!(sum, 3);
+///
+///     let x = 3;
+///     let product = tt_call! {
+///         macro = [{ tt_take_until }]
+///         condition = [{ tt_is_ident }]
+///         input = [{ 2 * x }]
+///         ~~> select[..]
+///     };
+///     assert_eq!(product, 6);
+/// }
+/// ```
+///
+/// # Selecting and renaming return values
+///
+/// A macro that returns more than one value can still be expanded into the
+/// tokens of some of them by naming them with `select`. Here the tokens from the
+/// first comma onward are discarded.
+///
+/// Unlike selecting by position, selecting by name defines a helper macro that
+/// looks up the names, so the call must be in item or statement position, see
+/// [Position](#position). In an expression, put the call in a block as below.
+/// There is no such workaround in type position, where only `select[...]`
+/// can be used.
+///
+/// ```
+/// use tt_call::{tt_call, tt_is_comma, tt_take_until};
+///
+/// fn main() {
+///     let three = {
+///         tt_call! {
+///             macro = [{ tt_take_until }]
+///             condition = [{ tt_is_comma }]
+///             input = [{ 1 + 2, "ignored" }]
+///             ~~> select(prefix)
+///         }
+///     };
+///     assert_eq!(three, 3);
+/// }
+/// ```
+///
+/// Return values that are not listed in a `rename` table keep their name. This
+/// lets a return destination written against its own names accept the output
+/// of any macro, without an adapter macro in between.
+///
+/// ```
+/// use tt_call::{parse_type, tt_call};
+///
+/// macro_rules! print_field {
+///     {
+///         name = [{ $name:ident }]
+///         field_ty = [{ $($ty:tt)* }]
+///         rest = [{ }]
+///     } => {
+///         println!("{}: {}", stringify!($name), stringify!($($ty)*));
+///     };
+/// }
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ parse_type }]
+///         input = [{ Vec<u8> }]
+///         ~~> print_field! {
+///             name = [{ bytes }]
+///         }
+///         rename(type => field_ty)
+///     }
+/// }
+/// ```
+///
+/// The renaming is done by a helper macro that the call defines for its table,
+/// so a call with `rename` is always in item or statement position, see
+/// [Position](#position). Where the return destination expands to an
+/// expression, put the call in a block as in the `select` example above.
+///
+/// # Pipelines
+///
+/// Here the output of one `tt_replace!` is renamed to be the input of another,
+/// whose output goes to the final return destination. Without the pipeline,
+/// the first call would need a helper macro as its return destination to
+/// invoke `tt_call!` again for the second.
+///
+/// ```
+/// use tt_call::{tt_call, tt_debug, tt_replace, tt_return};
+///
+/// macro_rules! is_a {
+///     {
+///         $caller:tt
+///         input = [{ a }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ true }]
+///         }
+///     };
+///
+///     {
+///         $caller:tt
+///         input = [{ $other:tt }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ false }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! is_b {
+///     {
+///         $caller:tt
+///         input = [{ b }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ true }]
+///         }
+///     };
+///
+///     {
+///         $caller:tt
+///         input = [{ $other:tt }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ false }]
+///         }
+///     };
+/// }
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ tt_replace }]
+///         condition = [{ is_a }]
+///         replace_with = [{ b }]
+///         input = [{ a + b * c }]
+///         ~~> tt_replace! {
+///             condition = [{ is_b }]
+///             replace_with = [{ d }]
+///         }
+///         rename(tokens => input)
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// tokens = [{ d + d * c }]
+/// ```
+///
+/// Each stage expands inside of the one before it, so a long pipeline may need
+/// a higher `#![recursion_limit]`.
+///
+/// # Recursion budget
+///
+/// Macros that parse large inputs may run into the compiler's recursion limit,
+/// which fails with an error that does not say what was being parsed. Giving
+/// a `budget(...)` counts the levels of macro expansion spent on calls made on
+/// behalf of the call instead. Once the budget is used up, compilation fails
+/// with an error that names the innermost macro, the one whose call could not
+/// be made, and the tokens it was processing. A budget outside of the
+/// supported range is reported as such.
+///
+/// ```text
+/// error: recursion budget exhausted in `parse_type` at `<Option<Box<u8>>>`
+///  --> src/main.rs:4:5
+///   |
+/// 4 | /     tt_call! {
+/// 5 | |         macro = [{ parse_type }]
+/// 6 | |         budget(3)
+/// 7 | |         input = [{ Vec<Option<Box<u8>>> }]
+/// 8 | |         ~~> tt_debug
+/// 9 | |     }
+///   | |_____^
+/// ```
+///
+/// The budget is passed along in the `$caller` of the called macro. Every call
+/// made through `tt_call!` with that `$caller` as the first argument of the
+/// return destination uses up five levels, for the call and its return, and
+/// the budget that remains when the callee returns is handed back to the
+/// caller. The loops of the macros in this crate use up the budget in the same
+/// way for every token that they process. `tt_if!` does not take a `$caller`,
+/// so calls made through it in a macro of your own are not counted.
+///
+/// Levels of expansion that a macro spends without making calls are not
+/// counted either, so the budget should be well below the
+/// `#![recursion_limit]`. Raising the budget may also require raising the
+/// limit.
+///
+/// # Checking return values
+///
+/// A return destination whose rules do not match the names of the values
+/// returned by the macro fails with a "no rules expected the token" error
+/// somewhere inside of the macro, which can be hard to trace back to the
+/// mismatch. With `expect = [{ ... }]`, the names are checked before the
+/// values are passed to the return destination, and any difference is
+/// reported as an error that names the expected and the returned value.
+///
+/// ```
+/// use tt_call::{parse_type, tt_call};
+///
+/// macro_rules! print_type {
+///     {
+///         type = [{ $($ty:tt)* }]
+///         rest = [{ $($rest:tt)* }]
+///     } => {
+///         println!("parsed the type `{}`", stringify!($($ty)*));
+///     };
+/// }
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ parse_type }]
+///         expect = [{ type rest }]
+///         input = [{ Vec<u8> }]
+///         ~~> print_type
+///     }
+/// }
+/// ```
+///
+/// Had the call listed `ty rest` instead, the mismatch with the value called
+/// `type` that `parse_type!` returns would be reported at the call.
+///
+/// ```text
+/// error: expected return value `ty`, found `type`
+///   --> src/main.rs:13:5
+///    |
+/// 13 | /     tt_call! {
+/// 14 | |         macro = [{ parse_type }]
+/// 15 | |         expect = [{ ty rest }]
+/// 16 | |         input = [{ Vec<u8> }]
+/// 17 | |         ~~> print_type
+/// 18 | |     }
+///    | |_____^
+/// ```
+///
+/// # Position
+///
+/// A call that uses `expect`, `rename` or `select` with names defines a helper
+/// macro matching those names, so it must be in item or statement position as
+/// described [in the crate documentation][position]. Selecting a return value
+/// by position does not define a macro and works in any position.
+///
+/// [position]: index.html#position
 #[macro_export]
 macro_rules! tt_call {
+    // Call macro, check the names of its return values, and expand into the
+    // tokens of its one return value.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            budget($budget:tt)
+        )*
+        expect = [{ $($expect:ident)* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+    } => {
+        $crate::private_expect! {
+            ($)
+            expect = [{ $($expect)* }]
+            call = [{
+                macro = [{ $($m)::* }]
+                $(
+                    budget($budget)
+                )*
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+            return = [{ }]
+        }
+    };
+
+    // Call macro and check the names of its return values before passing them
+    // to the given return destination.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            budget($budget:tt)
+        )*
+        expect = [{ $($expect:ident)* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($return:tt)+
+    } => {
+        $crate::private_expect! {
+            ($)
+            expect = [{ $($expect)* }]
+            call = [{
+                macro = [{ $($m)::* }]
+                $(
+                    budget($budget)
+                )*
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+            return = [{ ~~> $($return)+ }]
+        }
+    };
+
+    // Call macro with a recursion budget and expand into the tokens of its one
+    // return value.
+    {
+        macro = [{ $($m:ident)::* }]
+        budget($budget:tt)
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_budget }]
+            call = [{ $($m)::* }]
+            budget = [{ $budget }]
+            inputs = [{
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+        }
+    };
+
+    // Call macro with a recursion budget and pass its return values to the
+    // given return destination.
+    {
+        macro = [{ $($m:ident)::* }]
+        budget($budget:tt)
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($return:tt)+
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_budget }]
+            call = [{ $($m)::* }]
+            budget = [{ $budget }]
+            inputs = [{
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+            ~~> $($return)+
+        }
+    };
+
     // Call macro and expand into the tokens of its one return value.
     {
         macro = [{ $($m:ident)::* }]
@@ -517,48 +981,297 @@ macro_rules! tt_call {
         }
     };
 
-    // Call macro and append its return values onto the invocation of the given
-    // return destination with caller.
+    // Call macro and expand into the tokens of the selected return value.
     {
         macro = [{ $($m:ident)::* }]
         $(
             $input:ident = [{ $($tokens:tt)* }]
         )*
-        ~~> $($return:ident)::* ! {
-            $caller:tt
-            $(
-                $name:ident = [{ $($state:tt)* }]
-            )*
-        }
+        ~~> select($($select:ident)+)
     } => {
-        $($m)::* ! {
-            (__tt_call_private $($return)::* ! {
-                $caller
+        $crate::private_select! {
+            ($)
+            select = [{ $($select)+ }]
+            call = [{
+                macro = [{ $($m)::* }]
                 $(
-                    $name = [{ $($state)* }]
+                    $input = [{ $($tokens)* }]
                 )*
-            })
+            }]
+        }
+    };
+
+    // Call macro and expand into the tokens of all of its return values.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> select[..]
+    } => {
+        $($m)::* ! {
+            (__tt_call_private $crate::private_select_all! {})
             $(
                 $input = [{ $($tokens)* }]
             )*
         }
     };
-}
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! tt_identity_return {
-    // Macro returned one value.
+    // Call macro and expand into the tokens of the return value at the given
+    // position, counting from 1.
     {
-        $name:ident = [{ $($output:tt)* }]
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> select[$index:tt]
     } => {
-        $($output)*
+        $crate::tt_call! {
+            macro = [{ $crate::private_unary }]
+            input = [{ $index }]
+            ~~> $crate::private_select_index! {
+                index = [{ $index }]
+                call = [{
+                    macro = [{ $($m)::* }]
+                    $(
+                        $input = [{ $($tokens)* }]
+                    )*
+                }]
+            }
+        }
     };
 
-    // Macro parsed the entire input and returned one value.
+    // Unexpected: position written like a name.
     {
-        $name:ident = [{ $($output:tt)* }]
-        rest = [{ }]
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> select($index:tt)
+    } => {
+        compile_error! {
+            concat!(
+                "return values are selected by position with `select[",
+                stringify!($index),
+                "]`",
+            )
+        }
+    };
+
+    // Call macro, rename its return values, and pass them to the given return
+    // destination.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($return:ident)::* rename($($rename:tt)*)
+    } => {
+        $crate::private_rename_outputs! {
+            ($)
+            caller = [{ (__tt_call_private $($return)::* ! { }) }]
+            table = [{ $($rename)* }]
+            call = [{
+                macro = [{ $($m)::* }]
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+        }
+    };
+
+    // Same as above but the return destination has its own arguments.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($return:ident)::* ! { $($state:tt)* } rename($($rename:tt)*)
+    } => {
+        $crate::private_rename_outputs! {
+            ($)
+            caller = [{ (__tt_call_private $($return)::* ! { $($state)* }) }]
+            table = [{ $($rename)* }]
+            call = [{
+                macro = [{ $($m)::* }]
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+        }
+    };
+
+    // Call macro and pass its return values as inputs to the next macro in the
+    // pipeline.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($stage:ident)::*
+        ~~> $($rest:tt)+
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($m)::* }]
+            $(
+                $input = [{ $($tokens)* }]
+            )*
+            ~~> $crate::private_pipeline! {
+                macro = [{ $($stage)::* }]
+                args = [{ }]
+                then = [{ $($rest)+ }]
+            }
+        }
+    };
+
+    // Same as above but the next macro has its own arguments.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($stage:ident)::* ! { $($args:tt)* }
+        ~~> $($rest:tt)+
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($m)::* }]
+            $(
+                $input = [{ $($tokens)* }]
+            )*
+            ~~> $crate::private_pipeline! {
+                macro = [{ $($stage)::* }]
+                args = [{ $($args)* }]
+                then = [{ $($rest)+ }]
+            }
+        }
+    };
+
+    // Same as above but the return values are renamed first.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($stage:ident)::* rename($($rename:tt)*)
+        ~~> $($rest:tt)+
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($m)::* }]
+            $(
+                $input = [{ $($tokens)* }]
+            )*
+            ~~> $crate::private_pipeline! {
+                macro = [{ $($stage)::* }]
+                args = [{ }]
+                then = [{ $($rest)+ }]
+            }
+            rename($($rename)*)
+        }
+    };
+
+    // Same as above but the return values are renamed first and the next macro
+    // has its own arguments.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($stage:ident)::* ! { $($args:tt)* } rename($($rename:tt)*)
+        ~~> $($rest:tt)+
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($m)::* }]
+            $(
+                $input = [{ $($tokens)* }]
+            )*
+            ~~> $crate::private_pipeline! {
+                macro = [{ $($stage)::* }]
+                args = [{ $($args)* }]
+                then = [{ $($rest)+ }]
+            }
+            rename($($rename)*)
+        }
+    };
+
+    // Call macro and append its return values onto the invocation of the given
+    // return destination with caller, which carries a recursion budget. Use up
+    // part of the budget for the call and its return, and pass the remaining
+    // budget on to the macro together with its name, which is the one reported
+    // if the budget runs out while it is making calls of its own.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($return:ident)::* ! {
+            (__tt_call_private budget = [{ $($budget:tt)* }] within = [{ $($within:tt)* }] $($caller:tt)*)
+            $(
+                $name:ident = [{ $($state:tt)* }]
+            )*
+        }
+    } => {
+        $crate::private_budget_spend! {
+            budget = [{ $($budget)* }]
+            within = [{ $($within)* }]
+            call = [{ $($m)::* }]
+            caller = [{
+                within = [{ $($m)::* }] $($return)::* ! {
+                    (__tt_call_private budget = [{ }] within = [{ $($within)* }] $($caller)*)
+                    $(
+                        $name = [{ $($state)* }]
+                    )*
+                }
+            }]
+            tokens = [{
+                $(
+                    $input = [{ $($tokens)* }]
+                )*
+            }]
+        }
+    };
+
+    // Call macro and append its return values onto the invocation of the given
+    // return destination with caller.
+    {
+        macro = [{ $($m:ident)::* }]
+        $(
+            $input:ident = [{ $($tokens:tt)* }]
+        )*
+        ~~> $($return:ident)::* ! {
+            $caller:tt
+            $(
+                $name:ident = [{ $($state:tt)* }]
+            )*
+        }
+    } => {
+        $($m)::* ! {
+            (__tt_call_private $($return)::* ! {
+                $caller
+                $(
+                    $name = [{ $($state)* }]
+                )*
+            })
+            $(
+                $input = [{ $($tokens)* }]
+            )*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tt_identity_return {
+    // Macro returned one value.
+    {
+        $name:ident = [{ $($output:tt)* }]
+    } => {
+        $($output)*
+    };
+
+    // Macro parsed the entire input and returned one value.
+    {
+        $name:ident = [{ $($output:tt)* }]
+        rest = [{ }]
     } => {
         $($output)*
     };
@@ -574,6 +1287,833 @@ macro_rules! tt_identity_return {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_budget {
+    // Convert the budget to decimal digits.
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        budget = [{ $budget:tt }]
+        inputs = [{ $($inputs:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_decimal }]
+            input = [{ $budget }]
+            ~~> $crate::private_budget! {
+                $caller
+                call = [{ $($m)::* }]
+                budget = [{ $budget }]
+                inputs = [{ $($inputs)* }]
+            }
+        }
+    };
+
+    // Unexpected: budget is not an integer literal in the supported range.
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        budget = [{ $budget:tt }]
+        inputs = [{ $($inputs:tt)* }]
+        digits = [{ }]
+    } => {
+        compile_error! {
+            concat!(
+                "recursion budget must be an integer literal from 0 to 999, found `",
+                stringify!($budget),
+                "`",
+            )
+        }
+    };
+
+    // Convert each digit to unary, holding the units of each in a group of
+    // its own so that the budget never takes more than 27 tokens.
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        budget = [{ $budget:tt }]
+        inputs = [{ $($inputs:tt)* }]
+        digits = [{ $($digits:tt)+ }]
+    } => {
+        $crate::private_budget! {
+            $caller
+            call = [{ $($m)::* }]
+            inputs = [{ $($inputs)* }]
+            digits = [{ $($digits)+ }]
+            groups = [{ }]
+        }
+    };
+
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        inputs = [{ $($inputs:tt)* }]
+        digits = [{ $digit:tt $($digits:tt)* }]
+        groups = [{ $($groups:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_unary }]
+            input = [{ $digit }]
+            ~~> $crate::private_budget! {
+                $caller
+                call = [{ $($m)::* }]
+                inputs = [{ $($inputs)* }]
+                digits = [{ $($digits)* }]
+                groups = [{ $($groups)* }]
+            }
+        }
+    };
+
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        inputs = [{ $($inputs:tt)* }]
+        digits = [{ $($digits:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        unary = [{ $($units:tt)* }]
+    } => {
+        $crate::private_budget! {
+            $caller
+            call = [{ $($m)::* }]
+            inputs = [{ $($inputs)* }]
+            digits = [{ $($digits)* }]
+            groups = [{ $($groups)* [{ $($units)* }] }]
+        }
+    };
+
+    // Call the macro with a caller that carries the budget. Calls made on its
+    // behalf use up the budget, and the return values are passed on to our
+    // own caller. The caller is not the first argument of the return
+    // destination so that a budget of our own caller is not replaced by the
+    // remaining one.
+    {
+        $caller:tt
+        call = [{ $($m:ident)::* }]
+        inputs = [{ $($inputs:tt)* }]
+        digits = [{ }]
+        groups = [{ $($budget:tt)* }]
+    } => {
+        $($m)::* ! {
+            (__tt_call_private budget = [{ $($budget)* }] within = [{ $($m)::* }] $crate::private_budget! {
+                return = [{ $caller }]
+            })
+            $($inputs)*
+        }
+    };
+
+    // Return from the call. Pass the return values on to our caller.
+    {
+        return = [{ $caller:tt }]
+        $($outputs:tt)*
+    } => {
+        $crate::tt_return! {
+            $caller
+            $($outputs)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_budget_spend {
+    // The budget is held as the units of its three decimal digits, each in a
+    // group of its own. Take five units from the last digit.
+    {
+        budget = [{ [{ $($hundreds:tt)* }] [{ $($tens:tt)* }] [{ 1 1 1 1 1 $($ones:tt)* }] }]
+        within = [{ $($within:tt)* }]
+        call = [{ $($m:ident)::* }]
+        caller = [{ $($caller:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        $($m)::* ! {
+            (__tt_call_private budget = [{ [{ $($hundreds)* }] [{ $($tens)* }] [{ $($ones)* }] }] $($caller)*)
+            $($tokens)*
+        }
+    };
+
+    // Fewer than five units in the last digit. Borrow ten from the one before.
+    {
+        budget = [{ [{ $($hundreds:tt)* }] [{ 1 $($tens:tt)* }] [{ $($ones:tt)* }] }]
+        within = [{ $($within:tt)* }]
+        call = [{ $($m:ident)::* }]
+        caller = [{ $($caller:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        $($m)::* ! {
+            (__tt_call_private budget = [{ [{ $($hundreds)* }] [{ $($tens)* }] [{ $($ones)* 1 1 1 1 1 }] }] $($caller)*)
+            $($tokens)*
+        }
+    };
+
+    // No tens either. Borrow a hundred.
+    {
+        budget = [{ [{ 1 $($hundreds:tt)* }] [{ }] [{ $($ones:tt)* }] }]
+        within = [{ $($within:tt)* }]
+        call = [{ $($m:ident)::* }]
+        caller = [{ $($caller:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        $($m)::* ! {
+            (__tt_call_private budget = [{ [{ $($hundreds)* }] [{ 1 1 1 1 1 1 1 1 1 }] [{ $($ones)* 1 1 1 1 1 }] }] $($caller)*)
+            $($tokens)*
+        }
+    };
+
+    // Unexpected: fewer than five units left in the budget.
+    {
+        budget = [{ $($budget:tt)* }]
+        within = [{ $($within:tt)* }]
+        call = [{ $($m:ident)::* }]
+        caller = [{ $($caller:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ input }]
+            inputs = [{ $($tokens)* }]
+            rest = [{ $($tokens)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_budget_exhausted {
+    // Name the macro by the last segment of its path.
+    {
+        within = [{ $segment:ident :: $($within:tt)+ }]
+        $($rest:tt)*
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)+ }]
+            $($rest)*
+        }
+    };
+
+    // Look for the tokens that were being processed when the budget ran out
+    // under each of the names that the macros of this crate give them, in
+    // turn. Found them under the name `input`.
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ input }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ input = [{ $($point:tt)+ }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            point = [{ $($point)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ input }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ $name:ident = [{ $($value:tt)* }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ input }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ input }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ first }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($inputs)* }]
+        }
+    };
+
+    // Found the tokens under the name `first`.
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ first }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ first = [{ $($point:tt)+ }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            point = [{ $($point)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ first }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ $name:ident = [{ $($value:tt)* }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ first }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ first }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ rest }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($inputs)* }]
+        }
+    };
+
+    // Found the tokens under the name `rest`.
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ rest }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ rest = [{ $($point:tt)+ }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            point = [{ $($point)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ rest }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ $name:ident = [{ $($value:tt)* }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ rest }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ rest }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ tokens }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($inputs)* }]
+        }
+    };
+
+    // Found the tokens under the name `tokens`.
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ tokens }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ tokens = [{ $($point:tt)+ }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            point = [{ $($point)* }]
+        }
+    };
+
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ tokens }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ $name:ident = [{ $($value:tt)* }] $($rest:tt)* }]
+    } => {
+        $crate::private_budget_exhausted! {
+            within = [{ $($within)* }]
+            find = [{ tokens }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Unexpected: none of the inputs hold the tokens being processed.
+    {
+        within = [{ $($within:tt)* }]
+        find = [{ tokens }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ }]
+    } => {
+        compile_error! {
+            concat!("recursion budget exhausted in `", stringify!($($within)*), "`")
+        }
+    };
+
+    // Report the tokens together with the macro that was making the call.
+    {
+        within = [{ $($within:tt)* }]
+        point = [{ $($point:tt)* }]
+    } => {
+        compile_error! {
+            concat!(
+                "recursion budget exhausted in `",
+                stringify!($($within)*),
+                "` at `",
+                stringify!($($point)*),
+                "`",
+            )
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_pipeline {
+    {
+        macro = [{ $($m:ident)::* }]
+        args = [{ $($args:tt)* }]
+        then = [{ $($then:tt)* }]
+        $($outputs:tt)*
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($m)::* }]
+            $($args)*
+            $($outputs)*
+            ~~> $($then)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_expect {
+    // Define a macro with one rule per expected name that accepts a return
+    // value by that name, and call the macro with that one as the return
+    // destination. Once every name has been checked, the return values are
+    // passed on to the original return destination. See `private_eq!` for the
+    // use of `$d`.
+    {
+        ($d:tt)
+        expect = [{ $($expect:ident)* }]
+        call = [{ $($call:tt)* }]
+        return = [{ $($return:tt)* }]
+    } => {
+        macro_rules! __tt_call_expect {
+            $(
+                // Next return value has the expected name.
+                {
+                    __tt_expect
+                    expect = [{ $expect $d($d __tt_expect:ident)* }]
+                    outputs = [{ $d($d __tt_outputs:tt)* }]
+                    rest = [{ $expect = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+                } => {
+                    __tt_call_expect! {
+                        __tt_expect
+                        expect = [{ $d($d __tt_expect)* }]
+                        outputs = [{ $d($d __tt_outputs)* $expect = [{ $d($d __tt_value)* }] }]
+                        rest = [{ $d($d __tt_rest)* }]
+                    }
+                };
+            )*
+
+            // Every return value has the expected name. Pass them on.
+            {
+                __tt_expect
+                expect = [{ }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ $crate::private_return_values }]
+                    values = [{ $d($d __tt_outputs)* }]
+                    $($return)*
+                }
+            };
+
+            // Unexpected: return value has a different name.
+            {
+                __tt_expect
+                expect = [{ $d __tt_name:ident $d($d __tt_expect:ident)* }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ $d __tt_actual:ident = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+            } => {
+                compile_error! {
+                    concat!(
+                        "expected return value `",
+                        stringify!($d __tt_name),
+                        "`, found `",
+                        stringify!($d __tt_actual),
+                        "`",
+                    )
+                }
+            };
+
+            // Unexpected: fewer return values than expected.
+            {
+                __tt_expect
+                expect = [{ $d __tt_name:ident $d($d __tt_expect:ident)* }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ }]
+            } => {
+                compile_error! {
+                    concat!("missing return value `", stringify!($d __tt_name), "`")
+                }
+            };
+
+            // Unexpected: more return values than expected.
+            {
+                __tt_expect
+                expect = [{ }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ $d __tt_actual:ident = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+            } => {
+                compile_error! {
+                    concat!("unexpected return value `", stringify!($d __tt_actual), "`")
+                }
+            };
+
+            // Entry point. Receives the return values of the call.
+            {
+                $d($d __tt_outputs:tt)*
+            } => {
+                __tt_call_expect! {
+                    __tt_expect
+                    expect = [{ $($expect)* }]
+                    outputs = [{ }]
+                    rest = [{ $d($d __tt_outputs)* }]
+                }
+            };
+        }
+
+        $crate::tt_call! {
+            $($call)*
+            ~~> __tt_call_expect! {}
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_return_values {
+    {
+        $caller:tt
+        values = [{ $($values:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            $($values)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_select {
+    // Define a macro with one rule per selected name that looks up the return
+    // value by that name, and call the macro with that one as the return
+    // destination. The tokens of the selected return values are concatenated
+    // in the order that the names are listed. See `private_eq!` for the use of
+    // `$d`.
+    {
+        ($d:tt)
+        select = [{ $($select:ident)+ }]
+        call = [{ $($call:tt)* }]
+    } => {
+        macro_rules! __tt_call_select {
+            $(
+                // Found the next selected return value. Append its tokens and
+                // look up the next name from the beginning.
+                {
+                    __tt_select
+                    select = [{ $select $d($d __tt_select:ident)* }]
+                    tokens = [{ $d($d __tt_tokens:tt)* }]
+                    outputs = [{ $d($d __tt_outputs:tt)* }]
+                    rest = [{ $select = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+                } => {
+                    __tt_call_select! {
+                        __tt_select
+                        select = [{ $d($d __tt_select)* }]
+                        tokens = [{ $d($d __tt_tokens)* $d($d __tt_value)* }]
+                        outputs = [{ $d($d __tt_outputs)* }]
+                        rest = [{ $d($d __tt_outputs)* }]
+                    }
+                };
+            )*
+
+            // Next return value has a different name. Skip it.
+            {
+                __tt_select
+                select = [{ $d($d __tt_select:ident)+ }]
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ $d __tt_name:ident = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+            } => {
+                __tt_call_select! {
+                    __tt_select
+                    select = [{ $d($d __tt_select)* }]
+                    tokens = [{ $d($d __tt_tokens)* }]
+                    outputs = [{ $d($d __tt_outputs)* }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+
+            // Found every selected return value. Expand into their tokens.
+            {
+                __tt_select
+                select = [{ }]
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ $d($d __tt_rest:tt)* }]
+            } => {
+                $d($d __tt_tokens)*
+            };
+
+            // Unexpected: no return value has the selected name.
+            {
+                __tt_select
+                select = [{ $d __tt_name:ident $d($d __tt_select:ident)* }]
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ }]
+            } => {
+                compile_error! {
+                    concat!("missing return value `", stringify!($d __tt_name), "`")
+                }
+            };
+
+            // Entry point. Receives the return values of the call.
+            {
+                $d($d __tt_outputs:tt)*
+            } => {
+                __tt_call_select! {
+                    __tt_select
+                    select = [{ $($select)+ }]
+                    tokens = [{ }]
+                    outputs = [{ $d($d __tt_outputs)* }]
+                    rest = [{ $d($d __tt_outputs)* }]
+                }
+            };
+        }
+
+        $crate::tt_call! {
+            $($call)*
+            ~~> __tt_call_select! {}
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_select_index {
+    // Unexpected: positions count from 1.
+    {
+        index = [{ $index:tt }]
+        call = [{ $($call:tt)* }]
+        unary = [{ }]
+    } => {
+        compile_error! {
+            "return values are selected by position counting from 1"
+        }
+    };
+
+    // Converted the position to unary. Call macro and skip one return value
+    // per remaining unit.
+    {
+        index = [{ $index:tt }]
+        call = [{ $($call:tt)* }]
+        unary = [{ 1 $($skip:tt)* }]
+    } => {
+        $crate::tt_call! {
+            $($call)*
+            ~~> $crate::private_select_index! {
+                index = [{ $index }]
+                skip = [{ $($skip)* }]
+            }
+        }
+    };
+
+    // Not yet at the selected position. Skip the next return value.
+    {
+        index = [{ $index:tt }]
+        skip = [{ 1 $($skip:tt)* }]
+        $name:ident = [{ $($value:tt)* }]
+        $($rest:tt)*
+    } => {
+        $crate::private_select_index! {
+            index = [{ $index }]
+            skip = [{ $($skip)* }]
+            $($rest)*
+        }
+    };
+
+    // Arrived at the selected position. Expand into its tokens.
+    {
+        index = [{ $index:tt }]
+        skip = [{ }]
+        $name:ident = [{ $($value:tt)* }]
+        $($rest:tt)*
+    } => {
+        $($value)*
+    };
+
+    // Unexpected: fewer return values than the selected position.
+    {
+        index = [{ $index:tt }]
+        skip = [{ $($skip:tt)* }]
+    } => {
+        compile_error! {
+            concat!("missing return value at position ", stringify!($index))
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_select_all {
+    {
+        $(
+            $name:ident = [{ $($value:tt)* }]
+        )*
+    } => {
+        $(
+            $($value)*
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_rename_outputs {
+    // Define a macro with one rule per entry of the table that renames a
+    // return value, and call the macro with that one as the return
+    // destination. Return values that are not in the table keep their name.
+    // See `private_eq!` for the use of `$d`.
+    {
+        ($d:tt)
+        caller = [{ $caller:tt }]
+        rename = [{ $($from:ident => $to:ident)* }]
+        call = [{ $($call:tt)* }]
+    } => {
+        macro_rules! __tt_call_rename_outputs {
+            $(
+                // Next return value is renamed.
+                {
+                    __tt_rename_outputs
+                    $d __tt_caller:tt
+                    outputs = [{ $d($d __tt_outputs:tt)* }]
+                    rest = [{ $from = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+                } => {
+                    __tt_call_rename_outputs! {
+                        __tt_rename_outputs
+                        $d __tt_caller
+                        outputs = [{ $d($d __tt_outputs)* $to = [{ $d($d __tt_value)* }] }]
+                        rest = [{ $d($d __tt_rest)* }]
+                    }
+                };
+            )*
+
+            // Next return value keeps its name.
+            {
+                __tt_rename_outputs
+                $d __tt_caller:tt
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ $d __tt_name:ident = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+            } => {
+                __tt_call_rename_outputs! {
+                    __tt_rename_outputs
+                    $d __tt_caller
+                    outputs = [{ $d($d __tt_outputs)* $d __tt_name = [{ $d($d __tt_value)* }] }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+
+            // Renamed every return value. Pass them on.
+            {
+                __tt_rename_outputs
+                $d __tt_caller:tt
+                outputs = [{ $d($d __tt_outputs:tt)* }]
+                rest = [{ }]
+            } => {
+                $crate::tt_return! {
+                    $d __tt_caller
+                    $d($d __tt_outputs)*
+                }
+            };
+
+            // Entry point. Receives the return values of the call.
+            {
+                $d __tt_caller:tt
+                $d($d __tt_outputs:tt)*
+            } => {
+                __tt_call_rename_outputs! {
+                    __tt_rename_outputs
+                    $d __tt_caller
+                    outputs = [{ }]
+                    rest = [{ $d($d __tt_outputs)* }]
+                }
+            };
+        }
+
+        $crate::tt_call! {
+            $($call)*
+            ~~> __tt_call_rename_outputs! {
+                $caller
+            }
+        }
+    };
+
+    // Table as written by the caller, without a trailing comma.
+    {
+        ($d:tt)
+        caller = [{ $caller:tt }]
+        table = [{ $($from:ident => $to:ident),* }]
+        call = [{ $($call:tt)* }]
+    } => {
+        $crate::private_rename_outputs! {
+            ($d)
+            caller = [{ $caller }]
+            rename = [{ $($from => $to)* }]
+            call = [{ $($call)* }]
+        }
+    };
+
+    // Table as written by the caller, with a trailing comma.
+    {
+        ($d:tt)
+        caller = [{ $caller:tt }]
+        table = [{ $($from:ident => $to:ident,)+ }]
+        call = [{ $($call:tt)* }]
+    } => {
+        $crate::private_rename_outputs! {
+            ($d)
+            caller = [{ $caller }]
+            rename = [{ $($from => $to)* }]
+            call = [{ $($call)* }]
+        }
+    };
+
+    // Unexpected: table is not a comma separated list of `from => to` entries.
+    {
+        ($d:tt)
+        caller = [{ $caller:tt }]
+        table = [{ $($table:tt)* }]
+        call = [{ $($call:tt)* }]
+    } => {
+        compile_error! {
+            concat!(
+                "expected comma separated entries of the form `from => to`, found `",
+                stringify!($($table)*),
+                "`",
+            )
+        }
+    };
+}
+
 /// Return zero or more output values to the caller macro.
 ///
 /// # Input
@@ -642,6 +2182,53 @@ macro_rules! tt_return {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! private_return {
+    // Caller carries a recursion budget, and so does the caller of the return
+    // destination. Pass the remaining budget back to it.
+    {
+        (__tt_call_private budget = [{ $($budget:tt)* }] within = [{ $($within:tt)* }] $($caller:ident)::* ! {
+            (__tt_call_private budget = [{ $($old:tt)* }] within = [{ $($outer:tt)* }] $($next:tt)*)
+            $($state:tt)*
+        })
+        $($append:tt)*
+    } => {
+        $($caller)::* ! {
+            (__tt_call_private budget = [{ $($budget)* }] within = [{ $($outer)* }] $($next)*)
+            $($state)*
+            $($append)*
+        }
+    };
+
+    // Caller carries a recursion budget but the return destination does not
+    // take one. Drop it.
+    {
+        (__tt_call_private budget = [{ $($budget:tt)* }] within = [{ $($within:tt)* }] $($caller:ident)::* ! { $($state:tt)* })
+        $($append:tt)*
+    } => {
+        $($caller)::* ! {
+            $($state)*
+            $($append)*
+        }
+    };
+
+    // Caller was built by hand without going through `tt_call!`, and the
+    // caller of its return destination carries a recursion budget. Use up part
+    // of the budget for the call and its return.
+    {
+        (__tt_call_private $($caller:ident)::* ! {
+            (__tt_call_private budget = [{ $($budget:tt)* }] within = [{ $($within:tt)* }] $($next:tt)*)
+            $($state:tt)*
+        })
+        $($append:tt)*
+    } => {
+        $crate::private_budget_spend! {
+            budget = [{ $($budget)* }]
+            within = [{ $($within)* }]
+            call = [{ $($caller)::* }]
+            caller = [{ within = [{ $($within)* }] $($next)* }]
+            tokens = [{ $($state)* $($append)* }]
+        }
+    };
+
     {
         (__tt_call_private $($caller:ident)::* ! { $($state:tt)* })
         $($append:tt)*
@@ -658,14 +2245,17 @@ macro_rules! private_return {
 /// # Input
 ///
 ///   - `condition = [{` name of predicate macro to invoke `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
 ///   - `input = [{` arbitrary tokens to pass as input to the predicate `}]`
 ///   - `true = [{` tokens to expand to if the predicate returns true `}]`
 ///   - `false = [{` and if the predicate returns false `}]`
 ///
-/// The predicate macro must accept a single input value named `input`. It is
-/// expected to return a single output value which may have any name but must
-/// hold the tokens `true` or `false`. For example the built-in `tt_is_comma!`
-/// predicate expands to `is_comma = [{ true }]` or `is_comma = [{ false }]`.
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+/// For example the built-in `tt_is_comma!` predicate expands to
+/// `is_comma = [{ true }]` or `is_comma = [{ false }]`.
 ///
 /// # Example
 ///
@@ -716,12 +2306,14 @@ macro_rules! private_return {
 macro_rules! tt_if {
     {
         condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
         input = [{ $($input:tt)* }]
         true = [{ $($then:tt)* }]
         false = [{ $($else:tt)* }]
     } => {
         $crate::tt_call! {
             macro = [{ $($condition)::* }]
+            $($condition_args)*
             input = [{ $($input)* }]
             ~~> $crate::private_if_branch! {
                 true = [{ $($then)* }]
@@ -729,6 +2321,50 @@ macro_rules! tt_if {
             }
         }
     };
+
+    {
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+        true = [{ $($then:tt)* }]
+        false = [{ $($else:tt)* }]
+    } => {
+        $crate::tt_if! {
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+            true = [{ $($then)* }]
+            false = [{ $($else)* }]
+        }
+    };
+}
+
+// Same as `tt_if!` for tt-munchers of this crate, which pass their caller
+// along. The caller is kept as the first argument of the return destination
+// so that a recursion budget that it carries is used up by the condition, and
+// each branch is a macro invocation that receives the caller as its first
+// argument.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_if {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+        true = [{ $($then:tt)* }]
+        false = [{ $($else:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($condition)::* }]
+            $($condition_args)*
+            input = [{ $($input)* }]
+            ~~> $crate::private_if_branch! {
+                $caller
+                true = [{ $($then)* }]
+                false = [{ $($else)* }]
+            }
+        }
+    };
 }
 
 #[doc(hidden)]
@@ -751,6 +2387,32 @@ macro_rules! private_if_branch {
     } => {
         $($else)*
     };
+
+    // Branch condition of `private_if!` returned true. Pass the caller on.
+    {
+        $caller:tt
+        true = [{ $($then:ident)::* ! { $($state:tt)* } }]
+        false = [{ $($else:tt)* }]
+        $condition:ident = [{ true }]
+    } => {
+        $($then)::* ! {
+            $caller
+            $($state)*
+        }
+    };
+
+    // Branch condition of `private_if!` returned false. Pass the caller on.
+    {
+        $caller:tt
+        true = [{ $($then:tt)* }]
+        false = [{ $($else:ident)::* ! { $($state:tt)* } }]
+        $condition:ident = [{ false }]
+    } => {
+        $($else)::* ! {
+            $caller
+            $($state)*
+        }
+    };
 }
 
 /// Print arbitrary output values returned by a tt-call macro. This is valuable
@@ -0,0 +1,212 @@
+/// Invoke a tt-call macro on every element of a list and collect the results.
+/// <sup>**[tt-call]**</sup>
+///
+/// # Input
+///
+///   - `map = [{` name of macro to invoke on each element `}]`
+///   - `args = [{` key-value inputs to pass along with every element `}]`
+///     &emsp;&emsp;(optional)
+///   - `input = [{` zero or more elements of the form `[{` arbitrary tokens
+///     `}]` `}]`
+///
+/// The macro is invoked with the inputs given in `args`, if any, followed by
+/// an input value named `input` which receives the tokens of one element. It
+/// may return any number of output values.
+///
+/// # Output
+///
+///   - `mapped = [{` one `[{` outputs `}]` per element, in order `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_map, tt_return};
+///
+/// macro_rules! double {
+///     {
+///         $caller:tt
+///         input = [{ $($expr:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             value = [{ 2 * ($($expr)*) }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! sum_of_doubles {
+///     ($([$($expr:tt)*])*) => {
+///         tt_call! {
+///             macro = [{ tt_map }]
+///             map = [{ double }]
+///             input = [{ $( [{ $($expr)* }] )* }]
+///             ~~> sum_of_doubles_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! sum_of_doubles_return {
+///     {
+///         mapped = [{ $( [{ value = [{ $($value:tt)* }] }] )* }]
+///     } => {
+///         0 $( + $($value)* )*
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(sum_of_doubles!([1] [2 + 3]), 12);
+/// }
+/// ```
+///
+/// Inputs other than the element itself are given in `args`. Here each element
+/// is passed through [`tt_replace!`] along with the same condition and
+/// replacement every time.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// ```
+/// use tt_call::{tt_call, tt_map, tt_replace, tt_return};
+///
+/// macro_rules! is_lowercase_self {
+///     {
+///         $caller:tt
+///         input = [{ self }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ true }]
+///         }
+///     };
+///
+///     {
+///         $caller:tt
+///         input = [{ $other:tt }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ false }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! closures {
+///     ($([$($expr:tt)*])*) => {
+///         tt_call! {
+///             macro = [{ tt_map }]
+///             map = [{ tt_replace }]
+///             args = [{
+///                 condition = [{ is_lowercase_self }]
+///                 replace_with = [{ __value }]
+///             }]
+///             input = [{ $( [{ $($expr)* }] )* }]
+///             ~~> closures_return! {
+///                 param = [{ __value }]
+///             }
+///         }
+///     };
+/// }
+///
+/// macro_rules! closures_return {
+///     {
+///         param = [{ $param:ident }]
+///         mapped = [{ $( [{ tokens = [{ $($expr:tt)* }] }] )* }]
+///     } => {
+///         [ $( |$param| $($expr)* ),* ]
+///     };
+/// }
+///
+/// fn main() {
+///     let closures: [fn(i32) -> i32; 2] = closures!([self + 1] [self * 2]);
+///     assert_eq!(closures[0](5), 6);
+///     assert_eq!(closures[1](5), 10);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_map {
+    {
+        $caller:tt
+        map = [{ $($map:ident)::* }]
+        args = [{ $( $arg:ident = [{ $($value:tt)* }] )* }]
+        input = [{ $( [{ $($element:tt)* }] )* }]
+    } => {
+        $crate::private_map! {
+            $caller
+            map = [{ $($map)::* }]
+            args = [{ $( $arg = [{ $($value)* }] )* }]
+            mapped = [{ }]
+            rest = [{ $( [{ $($element)* }] )* }]
+        }
+    };
+
+    {
+        $caller:tt
+        map = [{ $($map:ident)::* }]
+        input = [{ $( [{ $($element:tt)* }] )* }]
+    } => {
+        $crate::private_map! {
+            $caller
+            map = [{ $($map)::* }]
+            args = [{ }]
+            mapped = [{ }]
+            rest = [{ $( [{ $($element)* }] )* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_map {
+    // Arrived at end of input. Return to caller.
+    {
+        $caller:tt
+        map = [{ $($map:ident)::* }]
+        args = [{ $($args:tt)* }]
+        mapped = [{ $($mapped:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            mapped = [{ $($mapped)* }]
+        }
+    };
+
+    // Invoke the macro on the next element.
+    {
+        $caller:tt
+        map = [{ $($map:ident)::* }]
+        args = [{ $($args:tt)* }]
+        mapped = [{ $($mapped:tt)* }]
+        rest = [{ [{ $($element:tt)* }] $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($map)::* }]
+            $($args)*
+            input = [{ $($element)* }]
+            ~~> $crate::private_map! {
+                $caller
+                map = [{ $($map)::* }]
+                args = [{ $($args)* }]
+                mapped = [{ $($mapped)* }]
+                after_element = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Return from invoking the macro on one element.
+    {
+        $caller:tt
+        map = [{ $($map:ident)::* }]
+        args = [{ $($args:tt)* }]
+        mapped = [{ $($mapped:tt)* }]
+        after_element = [{ $($rest:tt)* }]
+        $($output:tt)*
+    } => {
+        $crate::private_map! {
+            $caller
+            map = [{ $($map)::* }]
+            args = [{ $($args)* }]
+            mapped = [{ $($mapped)* [{ $($output)* }] }]
+            rest = [{ $($rest)* }]
+        }
+    };
+}
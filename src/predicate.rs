@@ -146,3 +146,134 @@ macro_rules! tt_is_lifetime {
         }
     };
 }
+
+/// Predicate that accepts a single token and determines whether it is the same
+/// as an expected token given by the caller.
+/// <sup>**[tt-call]**</sup>
+///
+/// The expected token may be any single token tree: an identifier, keyword,
+/// lifetime, literal, punctuation like `=>` or `::`, or even a whole group.
+///
+/// # Input
+///
+///   - `expected = [{` a single token tree `}]`
+///   - `input = [{` a single token tree `}]`
+///
+/// # Output
+///
+///   - `is_eq = [{` either true or false `}]`
+///
+/// When used as the `condition` of a combinator like [`tt_replace!`], give the
+/// expected token in the combinator's `condition_args` input.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// The expected token is built into the rules of a helper macro, so the call
+/// must be in item or statement position, and so must any call to a combinator
+/// that uses `tt_eq!` as its condition; see [Position] in the crate
+/// documentation.
+///
+/// [Position]: index.html#position
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_eq};
+///
+/// macro_rules! same_token {
+///     ($expected:tt $input:tt) => {{
+///         tt_call! {
+///             macro = [{ tt_eq }]
+///             expected = [{ $expected }]
+///             input = [{ $input }]
+///         }
+///     }};
+/// }
+///
+/// fn main() {
+///     assert!(same_token!(foo foo));
+///     assert!(same_token!(=> =>));
+///     assert!(same_token!(self self));
+///     assert!(!same_token!(foo bar));
+///     assert!(!same_token!(1 1.0));
+/// }
+/// ```
+///
+/// Here every `plus` is replaced by `+` without writing a predicate for it.
+///
+/// ```
+/// use tt_call::{tt_call, tt_eq, tt_replace};
+///
+/// fn main() {
+///     let sum = {
+///         tt_call! {
+///             macro = [{ tt_replace }]
+///             condition = [{ tt_eq }]
+///             condition_args = [{ expected = [{ plus }] }]
+///             replace_with = [{ + }]
+///             input = [{ 1 plus (2 plus 3) * 4 }]
+///         }
+///     };
+///     assert_eq!(sum, 21);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_eq {
+    {
+        $caller:tt
+        expected = [{ $expected:tt }]
+        input = [{ $input:tt }]
+    } => {
+        $crate::private_eq! {
+            ($)
+            $caller
+            expected = [{ $expected }]
+            input = [{ $input }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_eq {
+    // Define a predicate whose first rule matches exactly the expected token,
+    // and invoke it on the input.
+    //
+    // A `$` written directly in the body of the generated macro would be taken
+    // as a metavariable of this macro instead, so the caller passes a `$` token
+    // in as `$d` and the generated rules write `$d caller:tt` where they mean
+    // `$caller:tt`. Every other macro that generates a macro does the same.
+    {
+        ($d:tt)
+        $caller:tt
+        expected = [{ $expected:tt }]
+        input = [{ $input:tt }]
+    } => {
+        macro_rules! __tt_call_eq {
+            {
+                $d caller:tt
+                input = [{ $expected }]
+            } => {
+                $crate::tt_return! {
+                    $d caller
+                    is_eq = [{ true }]
+                }
+            };
+
+            {
+                $d caller:tt
+                input = [{ $d other:tt }]
+            } => {
+                $crate::tt_return! {
+                    $d caller
+                    is_eq = [{ false }]
+                }
+            };
+        }
+
+        __tt_call_eq! {
+            $caller
+            input = [{ $input }]
+        }
+    };
+}
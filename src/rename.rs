@@ -0,0 +1,246 @@
+/// Rename identifiers according to a table of replacements.
+/// <sup>**[tt-call]**</sup>
+///
+/// Every occurrence of an identifier on the left-hand side of the table is
+/// replaced by the corresponding identifier on the right-hand side, including
+/// inside of parenthesized, square bracketed and curly braced groups at any
+/// depth. All renames happen in a single pass, so the result of one rename is
+/// never renamed again by another entry of the table. In particular
+/// `a => b, b => a` swaps the two identifiers.
+///
+/// This does the job of several calls to [`tt_replace!`] without a predicate
+/// macro for each one. Keywords like `self` count as identifiers and may appear
+/// on either side.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// # Input
+///
+///   - `rename = [{` comma separated entries of the form `from => to` `}]`
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// # Output
+///
+///   - `tokens = [{` tokens after renaming `}]`
+///
+/// The table is turned into the rules of a helper macro, so the call must be in
+/// item or statement position; see [Position] in the crate documentation.
+///
+/// [Position]: index.html#position
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_rename};
+///
+/// fn main() {
+///     let (x, y) = (1, 10);
+///
+///     let v = {
+///         tt_call! {
+///             macro = [{ tt_rename }]
+///             rename = [{ a => x, b => y }]
+///             input = [{ [a + b, (a * b)] }]
+///         }
+///     };
+///
+///     assert_eq!(v, [11, 10]);
+/// }
+/// ```
+///
+/// Renaming happens in a single pass.
+///
+/// ```
+/// use tt_call::{tt_call, tt_debug, tt_rename};
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ tt_rename }]
+///         rename = [{ a => b, b => a, self => __this }]
+///         input = [{ f(a, b) { self.a } }]
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// tokens = [{ f (b, a) { __this.b } }]
+/// ```
+#[macro_export]
+macro_rules! tt_rename {
+    {
+        $caller:tt
+        rename = [{ $($from:ident => $to:ident),* $(,)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_rename! {
+            ($)
+            $caller
+            rename = [{ $($from => $to)* }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_rename {
+    // Define a tt-muncher with one rule per entry of the table, and invoke it
+    // on the input. See `private_eq!` for the use of `$d`. Otherwise the
+    // generated rules mirror those of `private_replace!`.
+    {
+        ($d:tt)
+        $caller:tt
+        rename = [{ $($from:ident => $to:ident)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        macro_rules! __tt_call_rename {
+            // Arrived at end of input. Return to caller.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ }]
+            } => {
+                $crate::tt_return! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* }]
+                }
+            };
+
+            $(
+                // Next token is an identifier to rename.
+                {
+                    $d __tt_caller:tt
+                    tokens = [{ $d($d __tt_tokens:tt)* }]
+                    rest = [{ $from $d($d __tt_rest:tt)* }]
+                } => {
+                    __tt_call_rename! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* $to }]
+                        rest = [{ $d($d __tt_rest)* }]
+                    }
+                };
+            )*
+
+            // Next token tree is a parenthesized group. Recurse to rename
+            // contents.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ ( $d($d __tt_group:tt)* ) $d($d __tt_rest:tt)* }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ __tt_call_rename }]
+                    tokens = [{ }]
+                    rest = [{ $d($d __tt_group)* }]
+                    ~~> __tt_call_rename! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* }]
+                        after_paren = [{ $d($d __tt_rest)* }]
+                    }
+                }
+            };
+
+            // Return from renaming contents of parenthesized group.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                after_paren = [{ $d($d __tt_after:tt)* }]
+                tokens = [{ $d($d __tt_inside:tt)* }]
+            } => {
+                __tt_call_rename! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* ( $d($d __tt_inside)* ) }]
+                    rest = [{ $d($d __tt_after)* }]
+                }
+            };
+
+            // Next token tree is a square bracketed group. Recurse to rename
+            // contents.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ [ $d($d __tt_group:tt)* ] $d($d __tt_rest:tt)* }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ __tt_call_rename }]
+                    tokens = [{ }]
+                    rest = [{ $d($d __tt_group)* }]
+                    ~~> __tt_call_rename! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* }]
+                        after_bracket = [{ $d($d __tt_rest)* }]
+                    }
+                }
+            };
+
+            // Return from renaming contents of square bracketed group.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                after_bracket = [{ $d($d __tt_after:tt)* }]
+                tokens = [{ $d($d __tt_inside:tt)* }]
+            } => {
+                __tt_call_rename! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* [ $d($d __tt_inside)* ] }]
+                    rest = [{ $d($d __tt_after)* }]
+                }
+            };
+
+            // Next token tree is a curly braced group. Recurse to rename
+            // contents.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ { $d($d __tt_group:tt)* } $d($d __tt_rest:tt)* }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ __tt_call_rename }]
+                    tokens = [{ }]
+                    rest = [{ $d($d __tt_group)* }]
+                    ~~> __tt_call_rename! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* }]
+                        after_brace = [{ $d($d __tt_rest)* }]
+                    }
+                }
+            };
+
+            // Return from renaming contents of curly braced group.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                after_brace = [{ $d($d __tt_after:tt)* }]
+                tokens = [{ $d($d __tt_inside:tt)* }]
+            } => {
+                __tt_call_rename! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* { $d($d __tt_inside)* } }]
+                    rest = [{ $d($d __tt_after)* }]
+                }
+            };
+
+            // Next token is not renamed. Keep it and continue.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ $d __tt_first:tt $d($d __tt_rest:tt)* }]
+            } => {
+                __tt_call_rename! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* $d __tt_first }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+        }
+
+        __tt_call_rename! {
+            $caller
+            tokens = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+}
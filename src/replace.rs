@@ -5,87 +5,1722 @@
 /// # Input
 ///
 ///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
 ///   - `replace_with = [{` arbitrary tokens inserted when the predicate is true `}]`
+///   - `recurse = [{` delimiters of groups to replace inside of `}]`
+///     &emsp;&emsp;(optional)
+///   - `depth = [{` maximum number of nested groups to replace inside of `}]`
+///     &emsp;&emsp;(optional)
+///   - `mode = [{` `all` or `first` or `nth` followed by an integer literal
+///     `}]` &emsp;&emsp;(optional)
+///   - `count = [{` `unary` or `literal` `}]` &emsp;&emsp;(optional)
 ///   - `input = [{` arbitrary input tokens `}]`
 ///
-/// The predicate macro must accept a single input value named `input`. It is
-/// expected to return a single output value which may have any name but must
-/// hold the tokens `true` or `false`. For example the built-in `tt_is_ident!`
-/// predicate expands to `is_ident = [{ true }]` or `is_ident = [{ false }]`.
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+/// For example the built-in `tt_is_ident!` predicate expands to
+/// `is_ident = [{ true }]` or `is_ident = [{ false }]`.
+///
+/// By default the contents of parenthesized, square bracketed and curly braced
+/// groups are replaced recursively at any depth. The `recurse` input lists the
+/// kinds of groups to descend into, written as empty delimiters. For example
+/// `recurse = [{ () [] }]` leaves the contents of curly braces alone, and
+/// `recurse = [{ }]` replaces only tokens that are not inside of any group. The
+/// `depth` input is an integer literal from 0 to 64 giving how many levels of
+/// nested groups may be entered, so `depth = [{ 1 }]` replaces inside of
+/// top-level groups but not inside of groups within those. A group that is not
+/// descended into is kept unchanged and is not passed to the predicate.
+///
+/// The `mode` input selects which matches are replaced. By default every match
+/// is replaced. With `mode = [{ first }]` only the first one is, and with
+/// `mode = [{ nth 3 }]` only the third one is, counting from 1 in the order
+/// that the tokens appear in the input including inside of groups. Tokens
+/// after the selected match are not passed to the predicate.
+///
+/// All inputs, required and optional, may be given in any order.
+///
+/// # Output
+///
+///   - `tokens = [{` tokens after replacement `}]`
+///   - `count = [{` number of replacements made `}]` &emsp;&emsp;(only if
+///     requested)
+///
+/// The `count` output is present only if the `count` input was given. With
+/// `count = [{ unary }]` it holds one `1` token per replacement, which a macro
+/// can turn into an integer expression with `0 $(+ $one)*` or can match on to
+/// detect that nothing was replaced. With `count = [{ literal }]` it holds a
+/// single integer literal, which is supported for up to 32 replacements. More
+/// replacements than that fail to compile with an error suggesting
+/// `count = [{ unary }]`, which has no limit.
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_replace, tt_return};
+///
+/// macro_rules! is_lowercase_self {
+///     {
+///         $caller:tt
+///         input = [{ self }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ true }]
+///         }
+///     };
+///
+///     {
+///         $caller:tt
+///         input = [{ $other:tt }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             is = [{ false }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! closure {
+///     ($($expr:tt)+) => {
+///         |__value| tt_call! {
+///             macro = [{ tt_replace }]
+///             condition = [{ is_lowercase_self }]
+///             replace_with = [{ __value }]
+///             input = [{ $($expr)+ }]
+///         }
+///     };
+/// }
+///
+/// fn main() {
+///     let add_one = closure!(self + 1);
+///     println!("{}", add_one(1));
+/// }
+/// ```
+///
+/// Here the contents of the curly braced block are left untouched.
+///
+/// ```
+/// use tt_call::{tt_call, tt_debug, tt_replace, tt_return};
+///
+/// # macro_rules! is_lowercase_self {
+/// #     {
+/// #         $caller:tt
+/// #         input = [{ self }]
+/// #     } => {
+/// #         tt_return! {
+/// #             $caller
+/// #             is = [{ true }]
+/// #         }
+/// #     };
+/// #
+/// #     {
+/// #         $caller:tt
+/// #         input = [{ $other:tt }]
+/// #     } => {
+/// #         tt_return! {
+/// #             $caller
+/// #             is = [{ false }]
+/// #         }
+/// #     };
+/// # }
+/// #
+/// fn main() {
+///     tt_call! {
+///         macro = [{ tt_replace }]
+///         condition = [{ is_lowercase_self }]
+///         replace_with = [{ __value }]
+///         input = [{ f(self, [self]) { self } }]
+///         recurse = [{ () [] }]
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// tokens = [{ f (__value, [__value]) { self } }]
+/// ```
+///
+/// Here only the first `self` is replaced, and the number of replacements is
+/// used to reject input that contains no `self` at all.
+///
+/// ```
+/// use tt_call::{tt_call, tt_replace, tt_return};
+///
+/// # macro_rules! is_lowercase_self {
+/// #     {
+/// #         $caller:tt
+/// #         input = [{ self }]
+/// #     } => {
+/// #         tt_return! {
+/// #             $caller
+/// #             is = [{ true }]
+/// #         }
+/// #     };
+/// #
+/// #     {
+/// #         $caller:tt
+/// #         input = [{ $other:tt }]
+/// #     } => {
+/// #         tt_return! {
+/// #             $caller
+/// #             is = [{ false }]
+/// #         }
+/// #     };
+/// # }
+/// #
+/// macro_rules! chain {
+///     ($value:expr => $($chain:tt)*) => {
+///         tt_call! {
+///             macro = [{ tt_replace }]
+///             condition = [{ is_lowercase_self }]
+///             replace_with = [{ __value }]
+///             mode = [{ first }]
+///             count = [{ unary }]
+///             input = [{ $($chain)* }]
+///             ~~> chain_return! {
+///                 param = [{ __value }]
+///                 value = [{ $value }]
+///             }
+///         }
+///     };
+/// }
+///
+/// macro_rules! chain_return {
+///     {
+///         param = [{ $param:ident }]
+///         value = [{ $value:expr }]
+///         tokens = [{ $($chain:tt)* }]
+///         count = [{ }]
+///     } => {
+///         compile_error!("chain must start with `self`")
+///     };
+///
+///     {
+///         param = [{ $param:ident }]
+///         value = [{ $value:expr }]
+///         tokens = [{ $($chain:tt)* }]
+///         count = [{ $one:tt }]
+///     } => {{
+///         let $param = $value;
+///         $($chain)*
+///     }};
+/// }
+///
+/// fn main() {
+///     let v = chain!(vec![3, 1, 2] => self.iter().map(|x| x * 2).collect::<Vec<_>>());
+///     assert_eq!(v, [6, 2, 4]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_replace {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        replace_with = [{ $($with:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            replace_with = [{ $($with)* }]
+            groups = [{ () [] {} }]
+            depth = [{ * }]
+            tally = [{ }]
+            nth = [{ * }]
+            count = [{ }]
+            tokens = [{ }]
+            rest = [{ $($input)* }]
+            ~~> $crate::private_replace_return! {
+                $caller
+                report = [{ }]
+            }
+        }
+    };
+
+    {
+        $caller:tt
+        $($inputs:tt)*
+    } => {
+        $crate::private_replace_inputs! {
+            $caller
+            $($inputs)*
+        }
+    };
+}
+
+crate::tt_signature! {
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! private_replace_inputs {
+        condition,
+        condition_args = [{ }],
+        replace_with,
+        recurse = [{ () [] {} }],
+        depth = [{ * }],
+        mode = [{ all }],
+        count = [{ }],
+        input,
+    } => $crate::private_replace_options
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_replace_options {
+    // Normalize the list of groups to descend into.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        recurse = [{ $($recurse:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        count = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace_groups }]
+            groups = [{ _ _ _ }]
+            rest = [{ $($recurse)* }]
+            ~~> $crate::private_replace_options! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                depth = [{ $($depth)* }]
+                mode = [{ $($mode)* }]
+                report = [{ $($report)* }]
+                input = [{ $($input)* }]
+            }
+        }
+    };
+
+    // Return from normalizing groups. There is no depth limit.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        depth = [{ * }]
+        mode = [{ $($mode:tt)* }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+    } => {
+        $crate::private_replace_options! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            mode = [{ $($mode)* }]
+            report = [{ $($report)* }]
+            input = [{ $($input)* }]
+            groups = [{ $($groups)* }]
+            unary = [{ * }]
+        }
+    };
+
+    // Return from normalizing groups. Convert the depth limit to unary.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_unary }]
+            input = [{ $($depth)* }]
+            ~~> $crate::private_replace_options! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                mode = [{ $($mode)* }]
+                report = [{ $($report)* }]
+                input = [{ $($input)* }]
+                groups = [{ $($groups)* }]
+            }
+        }
+    };
+
+    // Return from converting the depth limit. Replace every match.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        mode = [{ all }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        unary = [{ $($depth:tt)* }]
+    } => {
+        $crate::private_replace_options! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            report = [{ $($report)* }]
+            input = [{ $($input)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            nth = [{ all }]
+            unary = [{ * }]
+        }
+    };
+
+    // Return from converting the depth limit. Replace the first match.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        mode = [{ first }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        unary = [{ $($depth:tt)* }]
+    } => {
+        $crate::private_replace_options! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            report = [{ $($report)* }]
+            input = [{ $($input)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            nth = [{ first }]
+            unary = [{ 1 }]
+        }
+    };
+
+    // Return from converting the depth limit. Convert the index of the match
+    // to replace to unary.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        mode = [{ nth $n:tt }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        unary = [{ $($depth:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_unary }]
+            input = [{ $n }]
+            ~~> $crate::private_replace_options! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                report = [{ $($report)* }]
+                input = [{ $($input)* }]
+                groups = [{ $($groups)* }]
+                depth = [{ $($depth)* }]
+                nth = [{ $n }]
+            }
+        }
+    };
+
+    // Unexpected: there is no match number 0.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        nth = [{ $n:tt }]
+        unary = [{ }]
+    } => {
+        $crate::error_unexpected! {
+            $n
+        }
+    };
+
+    // All options are normalized and the number of replacements is not
+    // reported. Start replacing without counting them.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        report = [{ }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        nth = [{ $n:tt }]
+        unary = [{ $($nth:tt)+ }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ }]
+            nth = [{ $($nth)* }]
+            count = [{ }]
+            tokens = [{ }]
+            rest = [{ $($input)* }]
+            ~~> $crate::private_replace_return! {
+                $caller
+                report = [{ }]
+            }
+        }
+    };
+
+    // All options are normalized. Start replacing, counting each replacement
+    // with a `1`.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        report = [{ $($report:tt)* }]
+        input = [{ $($input:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        nth = [{ $n:tt }]
+        unary = [{ $($nth:tt)+ }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ 1 }]
+            nth = [{ $($nth)* }]
+            count = [{ }]
+            tokens = [{ }]
+            rest = [{ $($input)* }]
+            ~~> $crate::private_replace_return! {
+                $caller
+                report = [{ $($report)* }]
+            }
+        }
+    };
+}
+
+// Normalizes a list of empty delimiters like `{} ()` into three positions
+// holding `()`, `[]`, `{}` or `_` for each kind of group that is absent.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_replace_groups {
+    // Arrived at end of list. Return to caller.
+    {
+        $caller:tt
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            groups = [{ $paren $bracket $brace }]
+        }
+    };
+
+    // Parentheses.
+    {
+        $caller:tt
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        rest = [{ () $($rest:tt)* }]
+    } => {
+        $crate::private_replace_groups! {
+            $caller
+            groups = [{ () $bracket $brace }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Square brackets.
+    {
+        $caller:tt
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        rest = [{ [] $($rest:tt)* }]
+    } => {
+        $crate::private_replace_groups! {
+            $caller
+            groups = [{ $paren [] $brace }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Curly braces.
+    {
+        $caller:tt
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        rest = [{ {} $($rest:tt)* }]
+    } => {
+        $crate::private_replace_groups! {
+            $caller
+            groups = [{ $paren $bracket {} }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Unexpected: anything other than empty delimiters.
+    {
+        $caller:tt
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        rest = [{ $($unexpected:tt)+ }]
+    } => {
+        $crate::error_unexpected! {
+            $($unexpected)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_replace_return {
+    // Return tokens only.
+    {
+        $caller:tt
+        report = [{ }]
+        tokens = [{ $($tokens:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+        }
+    };
+
+    // Return tokens and the number of replacements in unary.
+    {
+        $caller:tt
+        report = [{ unary }]
+        tokens = [{ $($tokens:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+            count = [{ $($count)* }]
+        }
+    };
+
+    // Unexpected: too many replacements to convert to an integer literal.
+    {
+        $caller:tt
+        report = [{ literal }]
+        tokens = [{ $($tokens:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{
+            $c0:tt $c1:tt $c2:tt $c3:tt $c4:tt $c5:tt $c6:tt $c7:tt
+            $c8:tt $c9:tt $c10:tt $c11:tt $c12:tt $c13:tt $c14:tt $c15:tt
+            $c16:tt $c17:tt $c18:tt $c19:tt $c20:tt $c21:tt $c22:tt $c23:tt
+            $c24:tt $c25:tt $c26:tt $c27:tt $c28:tt $c29:tt $c30:tt $c31:tt
+            $($more:tt)+
+        }]
+    } => {
+        compile_error! {
+            "more than 32 replacements, which is too many for `count = [{ literal }]`; use `count = [{ unary }]` instead"
+        }
+    };
+
+    // Convert the number of replacements to an integer literal.
+    {
+        $caller:tt
+        report = [{ literal }]
+        tokens = [{ $($tokens:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_literal }]
+            unary = [{ $($count)* }]
+            ~~> $crate::private_replace_return! {
+                $caller
+                tokens = [{ $($tokens)* }]
+            }
+        }
+    };
+
+    // Return from converting the number of replacements.
+    {
+        $caller:tt
+        tokens = [{ $($tokens:tt)* }]
+        literal = [{ $count:tt }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+            count = [{ $count }]
+        }
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_replace {
+    // Arrived at end of input. Return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+        }
+    };
+
+    // The only match to replace has been replaced. Return to caller with the
+    // remaining tokens unchanged.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* $($rest)* }]
+            nth = [{ }]
+            count = [{ $($count)* }]
+        }
+    };
+
+    // At least 16 token trees remain. Replace within the next 16 as one chunk.
+    // The accumulated tokens and the rest of the input are carried along
+    // inside of the caller so that they are not matched again for every token
+    // of the chunk.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $t9:tt $t10:tt $t11:tt $t12:tt $t13:tt $t14:tt $t15:tt $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace_chunk }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ }]
+            rest = [{ $t0 $t1 $t2 $t3 $t4 $t5 $t6 $t7 $t8 $t9 $t10 $t11 $t12 $t13 $t14 $t15 }]
+            ~~> $crate::private_replace! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                groups = [{ $($groups)* }]
+                depth = [{ $($depth)* }]
+                tally = [{ $($tally)* }]
+                tokens = [{ $($tokens)* }]
+                after_chunk = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Fewer than 16 token trees remain. Replace within all of them as the last
+    // chunk, which returns straight to the caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)+ }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Return from replacing within a chunk.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_chunk = [{ $($after:tt)* }]
+        tokens = [{ $($chunk:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::private_replace! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* $($chunk)* }]
+            rest = [{ $($after)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_replace_chunk {
+    // Arrived at end of chunk. Return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+        }
+    };
+
+    // The only match to replace has been replaced. Return to caller with the
+    // remaining tokens of the chunk unchanged.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* $($rest)* }]
+            nth = [{ }]
+            count = [{ $($count)* }]
+        }
+    };
+
+    // The next three rules take apart a group into its delimiter and contents,
+    // and the three after the recursive rules put them back together after
+    // returning from replacing within the contents. These are the only rules
+    // that depend on the kind of delimiter. The delimiter is given as `_` if
+    // groups of this kind are not descended into.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ ( $($group:tt)* ) $($rest:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $paren $bracket $brace }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            group = [{ ( $($group)* ) }]
+            delimiter = [{ $paren }]
+            contents = [{ $($group)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ [ $($group:tt)* ] $($rest:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $paren $bracket $brace }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            group = [{ [ $($group)* ] }]
+            delimiter = [{ $bracket }]
+            contents = [{ $($group)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $paren:tt $bracket:tt $brace:tt }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ { $($group:tt)* } $($rest:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $paren $bracket $brace }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            group = [{ { $($group)* } }]
+            delimiter = [{ $brace }]
+            contents = [{ $($group)* }]
+        }
+    };
+
+    // Next token tree is a group that is not descended into. Keep it
+    // unchanged.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        group = [{ $group:tt }]
+        delimiter = [{ _ }]
+        contents = [{ $($contents:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* $group }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Next token tree is a group. Recurse to replace contents.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ * }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        group = [{ $group:tt }]
+        delimiter = [{ $delimiter:tt }]
+        contents = [{ $($contents:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ * }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ }]
+            rest = [{ $($contents)* }]
+            ~~> $crate::private_replace_chunk! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                groups = [{ $($groups)* }]
+                depth = [{ * }]
+                tally = [{ $($tally)* }]
+                tokens = [{ $($tokens)* }]
+                after_group = [{ $($rest)* }]
+                delimiter = [{ $delimiter }]
+            }
+        }
+    };
+
+    // Next token tree is a group within the depth limit. Recurse to replace
+    // contents one level deeper.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ 1 $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        group = [{ $group:tt }]
+        delimiter = [{ $delimiter:tt }]
+        contents = [{ $($contents:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_replace }]
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ }]
+            rest = [{ $($contents)* }]
+            ~~> $crate::private_replace_chunk! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                groups = [{ $($groups)* }]
+                depth = [{ 1 $($depth)* }]
+                tally = [{ $($tally)* }]
+                tokens = [{ $($tokens)* }]
+                after_group = [{ $($rest)* }]
+                delimiter = [{ $delimiter }]
+            }
+        }
+    };
+
+    // Next token tree is a group beyond the depth limit. Keep it unchanged.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        group = [{ $group:tt }]
+        delimiter = [{ $delimiter:tt }]
+        contents = [{ $($contents:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* $group }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_group = [{ $($after:tt)* }]
+        delimiter = [{ () }]
+        tokens = [{ $($inside:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* ( $($inside)* ) }]
+            rest = [{ $($after)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_group = [{ $($after:tt)* }]
+        delimiter = [{ [] }]
+        tokens = [{ $($inside:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* [ $($inside)* ] }]
+            rest = [{ $($after)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_group = [{ $($after:tt)* }]
+        delimiter = [{ {} }]
+        tokens = [{ $($inside:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* { $($inside)* } }]
+            rest = [{ $($after)* }]
+        }
+    };
+
+    // Next token is not a group. Invoke condition on it with the rules below as
+    // the return destination.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $($condition)::* ! {
+            (__tt_call_private $crate::private_replace_chunk! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                replace_with = [{ $($with)* }]
+                groups = [{ $($groups)* }]
+                depth = [{ $($depth)* }]
+                tally = [{ $($tally)* }]
+                nth = [{ $($nth)* }]
+                count = [{ $($count)* }]
+                tokens = [{ $($tokens)* }]
+                first = [{ $first }]
+                rest = [{ $($rest)* }]
+            })
+            $($condition_args)*
+            input = [{ $first }]
+        }
+    };
+
+    // Condition returned true while replacing every match. Replace the token
+    // and continue.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ * }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        first = [{ $first:tt }]
+        rest = [{ $($rest:tt)* }]
+        $is:ident = [{ true }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ * }]
+            count = [{ $($count)* $($tally)* }]
+            tokens = [{ $($tokens)* $($with)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Condition returned true for the nth match. Replace it and keep the rest
+    // of the chunk unchanged.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ 1 }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        first = [{ $first:tt }]
+        rest = [{ $($rest:tt)* }]
+        $is:ident = [{ true }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ }]
+            count = [{ $($count)* $($tally)* }]
+            tokens = [{ $($tokens)* $($with)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Condition returned true for a match before the nth. Keep it and count
+    // down.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ 1 $($later:tt)+ }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        first = [{ $first:tt }]
+        rest = [{ $($rest:tt)* }]
+        $is:ident = [{ true }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($later)+ }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* $first }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Condition returned false. Keep the token and continue.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        groups = [{ $($groups:tt)* }]
+        depth = [{ $($depth:tt)* }]
+        tally = [{ $($tally:tt)* }]
+        nth = [{ $($nth:tt)* }]
+        count = [{ $($count:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        first = [{ $first:tt }]
+        rest = [{ $($rest:tt)* }]
+        $is:ident = [{ false }]
+    } => {
+        $crate::private_replace_chunk! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            replace_with = [{ $($with)* }]
+            groups = [{ $($groups)* }]
+            depth = [{ $($depth)* }]
+            tally = [{ $($tally)* }]
+            nth = [{ $($nth)* }]
+            count = [{ $($count)* }]
+            tokens = [{ $($tokens)* $first }]
+            rest = [{ $($rest)* }]
+        }
+    };
+}
+
+/// Replace each occurrence of a fixed sequence of tokens by a given replacement
+/// sequence of tokens.
+/// <sup>**[tt-call]**</sup>
+///
+/// This is the multi-token counterpart of [`tt_replace!`]. The pattern is
+/// matched at every position of the input, and just like `tt_replace!` the
+/// contents of parenthesized, square bracketed and curly braced groups are
+/// searched recursively. A match cannot span the boundary of a group. After a
+/// replacement, matching resumes after the end of the matched tokens so the
+/// replacement itself is never rewritten.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// The pattern may contain wildcards written as `$name:tt`, each of which
+/// matches any single token tree. The tokens it matched can be referred to as
+/// `$name` in the replacement. Wildcard names beginning with `__tt` are
+/// reserved.
+///
+/// # Input
+///
+///   - `pattern = [{` nonempty sequence of tokens and wildcards to look for `}]`
+///   - `replace_with = [{` arbitrary tokens inserted in place of each match
+///     `}]`
+///   - `input = [{` arbitrary input tokens `}]`
 ///
 /// # Output
 ///
 ///   - `tokens = [{` tokens after replacement `}]`
 ///
+/// The pattern is turned into the rules of a helper macro, so the call must be
+/// in item or statement position; see [Position] in the crate documentation.
+/// The example below wraps it in a block to use the result as an expression.
+///
+/// [Position]: index.html#position
+///
+/// A wildcard written in the body of another `macro_rules!` macro would be
+/// interpreted by that macro. Pass the `$` token into such a macro as an
+/// argument instead, for example as `$d:tt`, and write wildcards as
+/// `$d name:tt`.
+///
 /// # Example
 ///
 /// ```
-/// use tt_call::{tt_call, tt_replace, tt_return};
+/// use tt_call::{tt_call, tt_replace_seq};
 ///
-/// macro_rules! is_lowercase_self {
-///     {
-///         $caller:tt
-///         input = [{ self }]
-///     } => {
-///         tt_return! {
-///             $caller
-///             is = [{ true }]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn main() {
+///     let p = Point { x: 1, y: 2 };
+///
+///     let sum = {
+///         tt_call! {
+///             macro = [{ tt_replace_seq }]
+///             pattern = [{ self . $field:tt }]
+///             replace_with = [{ p . $field }]
+///             input = [{ self.x + (self.y * 10) }]
 ///         }
 ///     };
 ///
+///     assert_eq!(sum, 21);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_replace_seq {
+    {
+        $caller:tt
+        pattern = [{ $($pattern:tt)+ }]
+        replace_with = [{ $($with:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_replace_seq! {
+            ($)
+            $caller
+            pattern = [{ $($pattern)* }]
+            replace_with = [{ $($with)* }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_replace_seq {
+    // Define a tt-muncher with the pattern built into one of its rules, and
+    // invoke it on the input. See `private_eq!` for the use of `$d`. Otherwise
+    // the generated rules mirror those of `private_replace!`.
+    {
+        ($d:tt)
+        $caller:tt
+        pattern = [{ $($pattern:tt)* }]
+        replace_with = [{ $($with:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        macro_rules! __tt_call_replace_seq {
+            // Arrived at end of input. Return to caller.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ }]
+            } => {
+                $crate::tt_return! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* }]
+                }
+            };
+
+            // Pattern matches at the current position. Replace.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ $($pattern)* $d($d __tt_rest:tt)* }]
+            } => {
+                __tt_call_replace_seq! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* $($with)* }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+
+            // Next token tree is a parenthesized group. Recurse to replace
+            // contents.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ ( $d($d __tt_group:tt)* ) $d($d __tt_rest:tt)* }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ __tt_call_replace_seq }]
+                    tokens = [{ }]
+                    rest = [{ $d($d __tt_group)* }]
+                    ~~> __tt_call_replace_seq! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* }]
+                        after_paren = [{ $d($d __tt_rest)* }]
+                    }
+                }
+            };
+
+            // Return from replacing contents of parenthesized group.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                after_paren = [{ $d($d __tt_after:tt)* }]
+                tokens = [{ $d($d __tt_inside:tt)* }]
+            } => {
+                __tt_call_replace_seq! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* ( $d($d __tt_inside)* ) }]
+                    rest = [{ $d($d __tt_after)* }]
+                }
+            };
+
+            // Next token tree is a square bracketed group. Recurse to replace
+            // contents.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ [ $d($d __tt_group:tt)* ] $d($d __tt_rest:tt)* }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ __tt_call_replace_seq }]
+                    tokens = [{ }]
+                    rest = [{ $d($d __tt_group)* }]
+                    ~~> __tt_call_replace_seq! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* }]
+                        after_bracket = [{ $d($d __tt_rest)* }]
+                    }
+                }
+            };
+
+            // Return from replacing contents of square bracketed group.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                after_bracket = [{ $d($d __tt_after:tt)* }]
+                tokens = [{ $d($d __tt_inside:tt)* }]
+            } => {
+                __tt_call_replace_seq! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* [ $d($d __tt_inside)* ] }]
+                    rest = [{ $d($d __tt_after)* }]
+                }
+            };
+
+            // Next token tree is a curly braced group. Recurse to replace
+            // contents.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ { $d($d __tt_group:tt)* } $d($d __tt_rest:tt)* }]
+            } => {
+                $crate::tt_call! {
+                    macro = [{ __tt_call_replace_seq }]
+                    tokens = [{ }]
+                    rest = [{ $d($d __tt_group)* }]
+                    ~~> __tt_call_replace_seq! {
+                        $d __tt_caller
+                        tokens = [{ $d($d __tt_tokens)* }]
+                        after_brace = [{ $d($d __tt_rest)* }]
+                    }
+                }
+            };
+
+            // Return from replacing contents of curly braced group.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                after_brace = [{ $d($d __tt_after:tt)* }]
+                tokens = [{ $d($d __tt_inside:tt)* }]
+            } => {
+                __tt_call_replace_seq! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* { $d($d __tt_inside)* } }]
+                    rest = [{ $d($d __tt_after)* }]
+                }
+            };
+
+            // Next token does not start a match. Keep it and continue.
+            {
+                $d __tt_caller:tt
+                tokens = [{ $d($d __tt_tokens:tt)* }]
+                rest = [{ $d __tt_first:tt $d($d __tt_rest:tt)* }]
+            } => {
+                __tt_call_replace_seq! {
+                    $d __tt_caller
+                    tokens = [{ $d($d __tt_tokens)* $d __tt_first }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+        }
+
+        __tt_call_replace_seq! {
+            $caller
+            tokens = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+}
+
+/// Replace each token that matches a given predicate by the output of a given
+/// tt-call macro invoked on that token.
+/// <sup>**[tt-call]**</sup>
+///
+/// This is like [`tt_replace!`] except that the replacement is computed
+/// separately for every matched token. Just like `tt_replace!`, the contents of
+/// parenthesized, square bracketed and curly braced groups are processed
+/// recursively and the groups themselves are never passed to the predicate.
+///
+/// [`tt_replace!`]: macro.tt_replace.html
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `map = [{` name of macro that computes the replacement `}]`
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+///
+/// The replacement macro must accept a single input value named `input`, which
+/// receives the matched token. It is expected to return a single output value
+/// which may have any name and holds the tokens to insert in place of the
+/// matched token.
+///
+/// # Output
+///
+///   - `tokens = [{` tokens after replacement `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_is_ident, tt_map_tokens, tt_return};
+///
+/// macro_rules! stringify_ident {
 ///     {
 ///         $caller:tt
-///         input = [{ $other:tt }]
+///         input = [{ $ident:ident }]
 ///     } => {
 ///         tt_return! {
 ///             $caller
-///             is = [{ false }]
-///         }
-///     };
-/// }
-///
-/// macro_rules! closure {
-///     ($($expr:tt)+) => {
-///         |__value| tt_call! {
-///             macro = [{ tt_replace }]
-///             condition = [{ is_lowercase_self }]
-///             replace_with = [{ __value }]
-///             input = [{ $($expr)+ }]
+///             tokens = [{ stringify!($ident) }]
 ///         }
 ///     };
 /// }
 ///
 /// fn main() {
-///     let add_one = closure!(self + 1);
-///     println!("{}", add_one(1));
+///     let names = tt_call! {
+///         macro = [{ tt_map_tokens }]
+///         condition = [{ tt_is_ident }]
+///         map = [{ stringify_ident }]
+///         input = [{ [a, b, (c)] }]
+///     };
+///     assert_eq!(names, ["a", "b", "c"]);
 /// }
 /// ```
 #[macro_export]
-macro_rules! tt_replace {
+macro_rules! tt_map_tokens {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         input = [{ $($input:tt)* }]
     } => {
-        $crate::private_replace! {
+        $crate::private_map_tokens! {
             $caller
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ }]
             rest = [{ $($input)* }]
         }
     };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        map = [{ $($map:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_map_tokens! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            map = [{ $($map)::* }]
+            input = [{ $($input)* }]
+        }
+    };
 }
 
 #[doc(hidden)]
 #[macro_export]
-macro_rules! private_replace {
+macro_rules! private_map_tokens {
     // Arrived at end of input. Return to caller.
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         rest = [{ }]
     } => {
@@ -99,20 +1734,23 @@ macro_rules! private_replace {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         rest = [{ ( $($group:tt)* ) $($rest:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::private_replace }]
+            macro = [{ $crate::private_map_tokens }]
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ }]
             rest = [{ $($group)* }]
-            ~~> $crate::private_replace! {
+            ~~> $crate::private_map_tokens! {
                 $caller
                 condition = [{ $($condition)::* }]
-                replace_with = [{ $($with)* }]
+                condition_args = [{ $($condition_args)* }]
+                map = [{ $($map)::* }]
                 tokens = [{ $($tokens)* }]
                 after_paren = [{ $($rest)* }]
             }
@@ -123,15 +1761,17 @@ macro_rules! private_replace {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         after_paren = [{ $($after:tt)* }]
         tokens = [{ $($inside:tt)* }]
     } => {
-        $crate::private_replace! {
+        $crate::private_map_tokens! {
             $caller
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ $($tokens)* ( $($inside)* ) }]
             rest = [{ $($after)* }]
         }
@@ -141,20 +1781,23 @@ macro_rules! private_replace {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         rest = [{ [ $($group:tt)* ] $($rest:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::private_replace }]
+            macro = [{ $crate::private_map_tokens }]
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ }]
             rest = [{ $($group)* }]
-            ~~> $crate::private_replace! {
+            ~~> $crate::private_map_tokens! {
                 $caller
                 condition = [{ $($condition)::* }]
-                replace_with = [{ $($with)* }]
+                condition_args = [{ $($condition_args)* }]
+                map = [{ $($map)::* }]
                 tokens = [{ $($tokens)* }]
                 after_bracket = [{ $($rest)* }]
             }
@@ -165,15 +1808,17 @@ macro_rules! private_replace {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         after_bracket = [{ $($after:tt)* }]
         tokens = [{ $($inside:tt)* }]
     } => {
-        $crate::private_replace! {
+        $crate::private_map_tokens! {
             $caller
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ $($tokens)* [ $($inside)* ] }]
             rest = [{ $($after)* }]
         }
@@ -183,20 +1828,23 @@ macro_rules! private_replace {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         rest = [{ { $($group:tt)* } $($rest:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::private_replace }]
+            macro = [{ $crate::private_map_tokens }]
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ }]
             rest = [{ $($group)* }]
-            ~~> $crate::private_replace! {
+            ~~> $crate::private_map_tokens! {
                 $caller
                 condition = [{ $($condition)::* }]
-                replace_with = [{ $($with)* }]
+                condition_args = [{ $($condition_args)* }]
+                map = [{ $($map)::* }]
                 tokens = [{ $($tokens)* }]
                 after_brace = [{ $($rest)* }]
             }
@@ -207,45 +1855,96 @@ macro_rules! private_replace {
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         after_brace = [{ $($after:tt)* }]
         tokens = [{ $($inside:tt)* }]
     } => {
-        $crate::private_replace! {
+        $crate::private_map_tokens! {
             $caller
             condition = [{ $($condition)::* }]
-            replace_with = [{ $($with)* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
             tokens = [{ $($tokens)* { $($inside)* } }]
             rest = [{ $($after)* }]
         }
     };
 
-    // Next token is not a group, invoke condition and continue.
+    // Return from computing the replacement of a matched token.
     {
         $caller:tt
         condition = [{ $($condition:ident)::* }]
-        replace_with = [{ $($with:tt)* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_token = [{ $($after:tt)* }]
+        $replacement:ident = [{ $($with:tt)* }]
+    } => {
+        $crate::private_map_tokens! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            map = [{ $($map)::* }]
+            tokens = [{ $($tokens)* $($with)* }]
+            rest = [{ $($after)* }]
+        }
+    };
+
+    // Condition matched the next token. Compute its replacement.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
+        tokens = [{ $($tokens:tt)* }]
+        matched = [{ $first:tt }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($map)::* }]
+            input = [{ $first }]
+            ~~> $crate::private_map_tokens! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                map = [{ $($map)::* }]
+                tokens = [{ $($tokens)* }]
+                after_token = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Next token is not a group, invoke condition and compute the replacement
+    // if it matches.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        map = [{ $($map:ident)::* }]
         tokens = [{ $($tokens:tt)* }]
         rest = [{ $first:tt $($rest:tt)* }]
     } => {
-        $crate::tt_if! {
+        $crate::private_if! {
+            $caller
             condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
             input = [{ $first }]
             true = [{
-                $crate::private_replace! {
-                    $caller
+                $crate::private_map_tokens! {
                     condition = [{ $($condition)::* }]
-                    replace_with = [{ $($with)* }]
-                    tokens = [{ $($tokens)* $($with)* }]
+                    condition_args = [{ $($condition_args)* }]
+                    map = [{ $($map)::* }]
+                    tokens = [{ $($tokens)* }]
+                    matched = [{ $first }]
                     rest = [{ $($rest)* }]
                 }
             }]
             false = [{
-                $crate::private_replace! {
-                    $caller
+                $crate::private_map_tokens! {
                     condition = [{ $($condition)::* }]
-                    replace_with = [{ $($with)* }]
+                    condition_args = [{ $($condition_args)* }]
+                    map = [{ $($map)::* }]
                     tokens = [{ $($tokens)* $first }]
                     rest = [{ $($rest)* }]
                 }
@@ -1,31 +1,17 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! private_parse_path {
-    // Entry point. Dup tokens.
-    {
-        $caller:tt
-        input = [{ $($input:tt)* }]
-    } => {
-        $crate::private_parse_path! {
-            $caller
-            tokens = [{ $($input)* }]
-            _tokens = [{ $($input)* }]
-        }
-    };
-
     // Parse absolute path.
     {
         $caller:tt
         tokens = [{ :: $_segment:ident $($rest:tt)* }]
         _tokens = [{ $colons:tt $segment:tt $($dup:tt)* }]
     } => {
-        $crate::tt_call! {
-            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
-            input = [{ $($rest)* }]
-            ~~> $crate::private_parse_path! {
-                $caller
-                prefix = [{ $colons $segment }]
-            }
+        $crate::private_parse_possibly_empty_path_after_ident! {
+            $caller
+            path = [{ $colons $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
 
@@ -35,48 +21,18 @@ macro_rules! private_parse_path {
         tokens = [{ $_segment:ident $($rest:tt)* }]
         _tokens = [{ $segment:tt $($dup:tt)* }]
     } => {
-        $crate::tt_call! {
-            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
-            input = [{ $($rest)* }]
-            ~~> $crate::private_parse_path! {
-                $caller
-                prefix = [{ $segment }]
-            }
-        }
-    };
-
-    // Return path.
-    {
-        $caller:tt
-        prefix = [{ $($prefix:tt)* }]
-        path = [{ $($path:tt)* }]
-        rest = [{ $($rest:tt)* }]
-    } => {
-        $crate::tt_return! {
+        $crate::private_parse_possibly_empty_path_after_ident! {
             $caller
-            path = [{ $($prefix)* $($path)* }]
-            rest = [{ $($rest)* }]
+            path = [{ $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
-
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! private_parse_possibly_empty_path_after_ident {
-    // Entry point. Dup tokens.
-    {
-        $caller:tt
-        input = [{ $($input:tt)* }]
-    } => {
-        $crate::private_parse_possibly_empty_path_after_ident! {
-            $caller
-            path = [{ }]
-            tokens = [{ $($input)* }]
-            _tokens = [{ $($input)* }]
-        }
-    };
-
     // Parse empty angle brackets.
     {
         $caller:tt
@@ -88,6 +44,7 @@ macro_rules! private_parse_possibly_empty_path_after_ident {
             $caller
             path = [{ $($path)* $lt $gt }]
             tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
 
@@ -113,6 +70,7 @@ macro_rules! private_parse_possibly_empty_path_after_ident {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_generic_param }]
             input = [{ $($rest)* }]
+            _input = [{ $($dup)* }]
             ~~> $crate::private_parse_in_angle_brackets! {
                 $caller
                 prefix = [{ $($path)* $lt }]
@@ -131,6 +89,7 @@ macro_rules! private_parse_possibly_empty_path_after_ident {
             $caller
             path = [{ $($path)* $colons $lt $gt }]
             tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
 
@@ -144,6 +103,7 @@ macro_rules! private_parse_possibly_empty_path_after_ident {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_generic_param }]
             input = [{ $($rest)* }]
+            _input = [{ $($dup)* }]
             ~~> $crate::private_parse_in_angle_brackets! {
                 $caller
                 prefix = [{ $($path)* $colons $lt }]
@@ -165,42 +125,72 @@ macro_rules! private_parse_possibly_empty_path_after_ident {
                 $caller
                 path = [{ $($path)* $original }]
                 tokens = [{ $($rest)* }]
+                _tokens = [{ $($dup)* }]
             }
         }
     };
 
-    // Anything after close angle is allowed after ident.
+    // Parse the next two path segments in one step.
     {
         $caller:tt
         path = [{ $($path:tt)* }]
-        tokens = [{ $($tokens:tt)* }]
-        _tokens = [{ $($dup:tt)* }]
+        tokens = [{ :: $_first:ident :: $_second:ident $($rest:tt)* }]
+        _tokens = [{ $colons:tt $first:tt $colons2:tt $second:tt $($dup:tt)* }]
     } => {
-        $crate::private_parse_possibly_empty_path_after_close_angle! {
+        $crate::private_parse_possibly_empty_path_after_ident! {
             $caller
-            path = [{ $($path)* }]
-            tokens = [{ $($tokens)* }]
+            path = [{ $($path)* $colons $first $colons2 $second }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
-}
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! private_parse_possibly_empty_path_after_close_angle {
-    // Entry point. Dup tokens.
+    // Parse the next path segment.
     {
         $caller:tt
         path = [{ $($path:tt)* }]
-        tokens = [{ $($tokens:tt)* }]
+        tokens = [{ :: $_segment:ident $($rest:tt)* }]
+        _tokens = [{ $colons:tt $segment:tt $($dup:tt)* }]
     } => {
-        $crate::private_parse_possibly_empty_path_after_close_angle! {
+        $crate::private_parse_possibly_empty_path_after_ident! {
+            $caller
+            path = [{ $($path)* $colons $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Unexpected: double colon is followed by something other than ident or
+    // angle bracket.
+    {
+        $caller:tt
+        path = [{ $($path:tt)* }]
+        tokens = [{ :: $($unexpected:tt)+ }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::error_unexpected! {
+            $($unexpected)*
+        }
+    };
+
+    // Not a double colon. End of path.
+    {
+        $caller:tt
+        path = [{ $($path:tt)* }]
+        tokens = [{ $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
             $caller
             path = [{ $($path)* }]
-            tokens = [{ $($tokens)* }]
-            _tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
         }
     };
+}
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_parse_possibly_empty_path_after_close_angle {
     // Parse path segment.
     {
         $caller:tt
@@ -212,7 +202,7 @@ macro_rules! private_parse_possibly_empty_path_after_close_angle {
             $caller
             path = [{ $($path)* $colons $segment }]
             tokens = [{ $($rest)* }]
-            _tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
 
@@ -235,7 +225,7 @@ macro_rules! private_parse_possibly_empty_path_after_close_angle {
         tokens = [{ $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             path = [{ $($path)* }]
             rest = [{ $($rest)* }]
@@ -262,18 +252,50 @@ macro_rules! private_parse_in_angle_brackets {
         }
     };
 
+    // Return from parsing a type parameter. Dup rest tokens.
+    {
+        $caller:tt
+        prefix = [{ $($path:tt)* }]
+        type = [{ $($param:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::private_parse_in_angle_brackets! {
+            $caller
+            prefix = [{ $($path)* }]
+            param = [{ $($param)* }]
+            rest = [{ $($rest)* }]
+            _rest = [{ $($rest)* }]
+        }
+    };
+
     // End of angle bracketed path parameters. Parse rest of path.
     {
         $caller:tt
         prefix = [{ $($path:tt)* }]
         param = [{ $($param:tt)* }]
-        rest = [{ > $($rest:tt)* }]
+        rest = [{ > :: $($rest:tt)* }]
         _rest = [{ $gt:tt $($dup:tt)* }]
     } => {
         $crate::private_parse_possibly_empty_path_after_close_angle! {
             $caller
             path = [{ $($path)* $($param)* $gt }]
-            tokens = [{ $($rest)* }]
+            tokens = [{ $($dup)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // End of angle bracketed path parameters and of the path.
+    {
+        $caller:tt
+        prefix = [{ $($path:tt)* }]
+        param = [{ $($param:tt)* }]
+        rest = [{ > $($rest:tt)* }]
+        _rest = [{ $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
+            $caller
+            path = [{ $($path)* $($param)* $gt }]
+            rest = [{ $($rest)* }]
         }
     };
 
@@ -285,7 +307,7 @@ macro_rules! private_parse_in_angle_brackets {
         rest = [{ >> $($rest:tt)* }]
         _rest = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             path = [{ $($path)* $($param)* > }]
             rest = [{ > $($rest)* }]
@@ -304,6 +326,7 @@ macro_rules! private_parse_in_angle_brackets {
             $caller
             path = [{ $($path)* $($param)* $comma $gt }]
             tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
 
@@ -315,7 +338,7 @@ macro_rules! private_parse_in_angle_brackets {
         rest = [{ , >> $($rest:tt)* }]
         _rest = [{ $comma:tt $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             path = [{ $($path)* $($param)* $comma > }]
             rest = [{ > $($rest)* }]
@@ -333,6 +356,7 @@ macro_rules! private_parse_in_angle_brackets {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_generic_param }]
             input = [{ $($rest)* }]
+            _input = [{ $($dup)* }]
             ~~> $crate::private_parse_in_angle_brackets! {
                 $caller
                 prefix = [{ $($path)* $($param)* $comma }]
@@ -375,25 +399,29 @@ macro_rules! private_parse_generic_param {
     {
         $caller:tt
         input = [{ $lifetime:lifetime $($rest:tt)* }]
+        _input = [{ $_lifetime:tt $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             param = [{ $lifetime }]
             rest = [{ $($rest)* }]
+            _rest = [{ $($dup)* }]
         }
     };
 
     // Parse associated type parameter.
     {
         $caller:tt
-        input = [{ $assoc:ident = $($rest:tt)+ }]
+        input = [{ $_assoc:ident = $($rest:tt)+ }]
+        _input = [{ $assoc:tt $eq:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_generic_param! {
                 $caller
-                assoc = [{ $assoc = }]
+                assoc = [{ $assoc $eq }]
             }
         }
     };
@@ -401,41 +429,202 @@ macro_rules! private_parse_generic_param {
     // Return from parsing associated type parameter.
     {
         $caller:tt
-        assoc = [{ $assoc:ident $eq:tt }]
+        assoc = [{ $assoc:tt $eq:tt }]
         type = [{ $($ty:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             param = [{ $assoc $eq $($ty)* }]
             rest = [{ $($rest)* }]
+            _rest = [{ $($rest)* }]
         }
     };
 
-    // Parse type parameter.
+    // Type parameter starts with `fn`. Parse as a type so that a keyword by
+    // itself is rejected rather than taken as a single identifier below.
     {
         $caller:tt
-        input = [{ $($input:tt)+ }]
+        input = [{ fn $($rest:tt)* }]
+        _input = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_with_plus! {
+            $caller
+            pieces = [{ }]
+            tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter starts with `dyn`. Parse as a type.
+    {
+        $caller:tt
+        input = [{ dyn $($rest:tt)* }]
+        _input = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_with_plus! {
+            $caller
+            pieces = [{ }]
+            tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter starts with `impl`. Parse as a type.
+    {
+        $caller:tt
+        input = [{ impl $($rest:tt)* }]
+        _input = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_with_plus! {
+            $caller
+            pieces = [{ }]
+            tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter starts with `for`. Parse as a type.
+    {
+        $caller:tt
+        input = [{ for $($rest:tt)* }]
+        _input = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_with_plus! {
+            $caller
+            pieces = [{ }]
+            tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter is a single identifier followed by comma. Return without
+    // going through parse_type.
+    {
+        $caller:tt
+        input = [{ $_ty:ident , $($rest:tt)* }]
+        _input = [{ $ty:tt $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
+            $caller
+            param = [{ $ty }]
+            rest = [{ $($dup)* }]
+            _rest = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter is a single identifier followed by close angle bracket.
+    {
+        $caller:tt
+        input = [{ $_ty:ident > $($rest:tt)* }]
+        _input = [{ $ty:tt $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
+            $caller
+            param = [{ $ty }]
+            rest = [{ $($dup)* }]
+            _rest = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter is a single identifier followed by `>>`.
+    {
+        $caller:tt
+        input = [{ $_ty:ident >> $($rest:tt)* }]
+        _input = [{ $ty:tt $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
+            $caller
+            param = [{ $ty }]
+            rest = [{ $($dup)* }]
+            _rest = [{ $($dup)* }]
+        }
+    };
+
+    // Type parameter is a path of more than one segment. Parse the path
+    // directly rather than through parse_type.
+    {
+        $caller:tt
+        input = [{ $_segment:ident :: $($rest:tt)* }]
+        _input = [{ $segment:tt $next:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::private_parse_type_with_plus }]
-            input = [{ $($input)* }]
+            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
+            path = [{ $segment }]
+            tokens = [{ $next $($rest)* }]
+            _tokens = [{ $next $($dup)* }]
             ~~> $crate::private_parse_generic_param! {
                 $caller
             }
         }
     };
 
-    // Return from parsing type parameter.
+    // Type parameter is a path with angle bracketed parameters.
     {
         $caller:tt
-        type = [{ $($ty:tt)* }]
+        input = [{ $_segment:ident < $($rest:tt)* }]
+        _input = [{ $segment:tt $next:tt $($dup:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
+            path = [{ $segment }]
+            tokens = [{ $next $($rest)* }]
+            _tokens = [{ $next $($dup)* }]
+            ~~> $crate::private_parse_generic_param! {
+                $caller
+            }
+        }
+    };
+
+    // Return from parsing a path. Dup the rest tokens.
+    {
+        $caller:tt
+        path = [{ $($path:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::private_parse_generic_param! {
+            $caller
+            path = [{ $($path)* }]
+            rest = [{ $($rest)* }]
+            _rest = [{ $($rest)* }]
+        }
+    };
+
+    // Path is followed by a plus. Parse the rest as a type with plus.
+    {
+        $caller:tt
+        path = [{ $($path:tt)* }]
+        rest = [{ + $($rest:tt)* }]
+        _rest = [{ $plus:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_with_plus! {
+            $caller
+            pieces = [{ $($path)* $plus }]
+            tokens = [{ $($rest)* }]
+        }
+    };
+
+    // Path is the whole type parameter. Return.
+    {
+        $caller:tt
+        path = [{ $($path:tt)* }]
         rest = [{ $($rest:tt)* }]
+        _rest = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
-            param = [{ $($ty)* }]
+            param = [{ $($path)* }]
             rest = [{ $($rest)* }]
+            _rest = [{ $($dup)* }]
+        }
+    };
+
+    // Parse type parameter. The type is returned directly to the caller.
+    {
+        $caller:tt
+        input = [{ $($input:tt)+ }]
+        _input = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_with_plus! {
+            $caller
+            pieces = [{ }]
+            tokens = [{ $($dup)* }]
         }
     };
 }
@@ -448,7 +637,7 @@ macro_rules! private_validate_fn_path_args {
         $caller:tt
         tokens = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -459,8 +648,9 @@ macro_rules! private_validate_fn_path_args {
         tokens = [{ $($rest:tt)+ }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_validate_fn_path_args! {
                 $caller
             }
@@ -473,7 +663,7 @@ macro_rules! private_validate_fn_path_args {
         type = [{ $($ty:tt)* }]
         rest = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -505,20 +695,6 @@ macro_rules! private_validate_fn_path_args {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! private_parse_path_after_fn_args {
-    // Entry point. Dup tokens.
-    {
-        $caller:tt
-        path = [{ $($path:tt)* }]
-        tokens = [{ $($tokens:tt)* }]
-    } => {
-        $crate::private_parse_path_after_fn_args! {
-            $caller
-            path = [{ $($path)* }]
-            tokens = [{ $($tokens)* }]
-            _tokens = [{ $($tokens)* }]
-        }
-    };
-
     // Parse function return type.
     {
         $caller:tt
@@ -527,8 +703,9 @@ macro_rules! private_parse_path_after_fn_args {
         _tokens = [{ $arrow:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_path_after_fn_args! {
                 $caller
                 path = [{ $($path)* $arrow }]
@@ -543,7 +720,7 @@ macro_rules! private_parse_path_after_fn_args {
         tokens = [{ $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             path = [{ $($path)* }]
             rest = [{ $($rest)* }]
@@ -557,7 +734,7 @@ macro_rules! private_parse_path_after_fn_args {
         type = [{ $($ret:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             path = [{ $($path)* $($ret)* }]
             rest = [{ $($rest)* }]
@@ -12,6 +12,15 @@
 ///   - `type = [{` tokens of type `}]`
 ///   - `rest = [{` remaining tokens after type `}]`
 ///
+/// Paths with their generic arguments, references, pointers, tuples and
+/// function pointers are parsed one path at a time rather than one token at a
+/// time. The 390-token `futures::MapErr<...>` type in this crate's tests, with
+/// generic arguments nested 7 deep, needs a depth of 104, under the default
+/// `#![recursion_limit]` of 128. Trait objects, qualified paths, slices and
+/// type macros are parsed token by token and need a deeper limit when they are
+/// large. So does any type when `tt_call!` is given a `budget(...)`, which is
+/// charged for each of those steps.
+///
 /// # Example
 ///
 /// ```
@@ -34,33 +43,1428 @@
 /// ```
 #[macro_export]
 macro_rules! parse_type {
+    // Caller carries a recursion budget. Use the full parser, which charges
+    // the budget on every call.
+    {
+        (__tt_call_private budget $($caller:tt)*)
+        input = [{ $($tt:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            (__tt_call_private budget $($caller)*)
+            tokens = [{ $($tt)* }]
+            _tokens = [{ $($tt)* }]
+        }
+    };
+
+    // Parse common types a whole path at a time.
+    {
+        $caller:tt
+        input = [{ $($tt:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($tt)* }]
+            step = [{ type }]
+            stack = [{ }]
+            type = [{ }]
+            tokens = [{ $($tt)* }]
+            _tokens = [{ $($tt)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_parse_type_fast {
+    // Takes a whole path together with the token after it in one step. Any
+    // shape of type not handled here is parsed by private_parse_type from the
+    // beginning of the input, which also reports any error.
+    //
+    // `step` is `type` where a type begins, `type start` where a generic
+    // argument, tuple element or function argument begins, `path` after a
+    // double colon, and `after ...` once a type is complete. `stack` holds a
+    // `<` for each open angle bracket and a frame for each group being checked,
+    // with the tokens to go back to after the group. Inside of a group the
+    // collected `type` is dropped, as the group was already added whole.
+    // A function pointer type is followed by a return type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after fn }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ -> $($rest:tt)* }]
+        _tokens = [{ $arrow:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $arrow }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Comma after a type inside of angle brackets or parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ , $($rest:tt)* }]
+        _tokens = [{ $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Close angle bracket after a type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ > $($rest:tt)* }]
+        _tokens = [{ $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Two close angle brackets after a type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ < < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ >> $($rest:tt)* }]
+        _tokens = [{ $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Close angle bracket and comma after a type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ < $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ > , $($rest:tt)* }]
+        _tokens = [{ $gt:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $gt $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Two close angle brackets and comma after a type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ < < $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ >> , $($rest:tt)* }]
+        _tokens = [{ $gt:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $gt $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // End of parentheses after a type. Continue after the parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ }]
+        _tokens = [{ }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path or type is followed by a double colon at the top level. Let the
+    // full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ :: $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Path at the top level is followed by parenthesized arguments or a bang.
+    // Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after path }]
+        stack = [{ }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ ($($args:tt)*) $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Type macro at the top level. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after path }]
+        stack = [{ }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ ! $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Type is complete at the top level. Return.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
+            $caller
+            type = [{ $($ty)* }]
+            rest = [{ $($dup)* }]
+        }
+    };
+
+    // Anything else after a type. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ after $kind:ident }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Close angle bracket and comma after an open angle bracket or comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ < $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ > , $($rest:tt)* }]
+        _tokens = [{ $gt:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $gt $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Close angle bracket after an open angle bracket or comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ > $($rest:tt)* }]
+        _tokens = [{ $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Two close angle brackets after an open angle bracket or comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ < < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ >> $($rest:tt)* }]
+        _tokens = [{ $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // End of parentheses after a trailing comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ }]
+        _tokens = [{ }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Lifetime parameter inside of angle brackets.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_lifetime:lifetime $($rest:tt)* }]
+        _tokens = [{ $lifetime:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after lifetime }]
+            stack = [{ < $($stack)* }]
+            type = [{ $($ty)* $lifetime }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Associated type binding inside of angle brackets.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_name:ident = $($rest:tt)* }]
+        _tokens = [{ $name:tt $eq:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ < $($stack)* }]
+            type = [{ $($ty)* $name $eq }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Named function argument.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ [{ fn $($frame:tt)* }] $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_name:ident : $($rest:tt)* }]
+        _tokens = [{ $name:tt $colon:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ [{ fn $($frame)* }] $($stack)* }]
+            type = [{ $($ty)* $name $colon }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Underscore named function argument.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type start }]
+        stack = [{ [{ fn $($frame:tt)* }] $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ _ : $($rest:tt)* }]
+        _tokens = [{ $name:tt $colon:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ [{ fn $($frame)* }] $($stack)* }]
+            type = [{ $($ty)* $name $colon }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Function pointer without arguments.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ fn () $($rest:tt)* }]
+        _tokens = [{ $fn:tt $args:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after fn }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $fn $args }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Function pointer. Check the arguments before going on after them.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ fn ($($_args:tt)+) $($rest:tt)* }]
+        _tokens = [{ $fn:tt $args:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{
+                [{
+                    fn
+                    type = [{ $($ty)* $fn $args }]
+                    tokens = [{ $($rest)* }]
+                    _tokens = [{ $($dup)* }]
+                }]
+                $($stack)*
+            }]
+            type = [{ }]
+            tokens = [{ $($_args)* }]
+            _tokens = [{ $($_args)* }]
+        }
+    };
+
+    // Any other type starting with `fn`. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ fn $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Any other type starting with `dyn`. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ dyn $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Any other type starting with `impl`. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ impl $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Any other type starting with `for`. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ for $($rest:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
+            $caller
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
+        }
+    };
+
+    // Unit type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ () $($rest:tt)* }]
+        _tokens = [{ $unit:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after tuple }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $unit }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Tuple or parenthesized type. Check the elements before going on after
+    // the parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ ($($_elements:tt)+) $($rest:tt)* }]
+        _tokens = [{ $tuple:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{
+                [{
+                    tuple
+                    type = [{ $($ty)* $tuple }]
+                    tokens = [{ $($rest)* }]
+                    _tokens = [{ $($dup)* }]
+                }]
+                $($stack)*
+            }]
+            type = [{ }]
+            tokens = [{ $($_elements)* }]
+            _tokens = [{ $($_elements)* }]
+        }
+    };
+
+    // Exclusive reference with a lifetime. Parse the element type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ & $_lifetime:lifetime mut $($rest:tt)* }]
+        _tokens = [{ $and:tt $lifetime:tt $mut:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $and $lifetime $mut }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Reference with a lifetime. Parse the element type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ & $_lifetime:lifetime $($rest:tt)* }]
+        _tokens = [{ $and:tt $lifetime:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $and $lifetime }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Exclusive reference. Parse the element type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ & mut $($rest:tt)* }]
+        _tokens = [{ $and:tt $mut:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $and $mut }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Reference. Parse the element type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ & $($rest:tt)* }]
+        _tokens = [{ $and:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $and }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Const pointer. Parse the element type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ * const $($rest:tt)* }]
+        _tokens = [{ $star:tt $const:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $star $const }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Mut pointer. Parse the element type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ * mut $($rest:tt)* }]
+        _tokens = [{ $star:tt $mut:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $star $mut }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // The never type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ ! $($rest:tt)* }]
+        _tokens = [{ $never:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after never }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $never }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // The underscore inferred type.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ _ $($rest:tt)* }]
+        _tokens = [{ $underscore:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after underscore }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $underscore }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Absolute path.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ type $($start:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ :: $_segment:ident $($rest:tt)* }]
+        _tokens = [{ $colons:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ path }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $colons }]
+            tokens = [{ $_segment $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of more than three segments. Take the first three.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident :: $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $c3:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ path }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c $c3 }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by an open angle bracket.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident < $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $lt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ < $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c $lt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by a comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident , $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by a close angle bracket and
+    // comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident > , $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $gt:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c $gt $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by a close angle bracket.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident > $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by two close angle brackets.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident >> $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by a trailing comma at the end of
+    // parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident , }]
+        _tokens = [{ $($_inner_dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments at the end of parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident }]
+        _tokens = [{ $($_inner_dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of three segments followed by something else. Look at the next
+    // token in the next step.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident :: $_c:ident $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $c2:tt $c:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after path }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $c2 $c }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments followed by an open angle bracket.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident < $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $lt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ < $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $lt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments followed by a comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident , $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments followed by a close angle bracket and
+    // comma.
     {
         $caller:tt
-        input = [{ $($tt:tt)* }]
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident > , $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $gt:tt $comma:tt $($dup:tt)* }]
     } => {
-        $crate::private_parse_type! {
+        $crate::private_parse_type_fast! {
             $caller
-            tokens = [{ $($tt)* }]
-            _tokens = [{ $($tt)* }]
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $gt $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
         }
     };
-}
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! private_parse_type_with_plus {
-    // Entry point.
+    // Path of two segments followed by a close angle bracket.
     {
         $caller:tt
         input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident > $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $gt:tt $($dup:tt)* }]
     } => {
-        $crate::private_parse_type_with_plus! {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments followed by two close angle brackets.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident >> $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments followed by a trailing comma at the end of
+    // parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident , }]
+        _tokens = [{ $($_inner_dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments at the end of parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident }]
+        _tokens = [{ $($_inner_dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of two segments followed by something else. Look at the next
+    // token in the next step.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident :: $_b:ident $($rest:tt)* }]
+        _tokens = [{ $a:tt $c1:tt $b:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after path }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $c1 $b }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by an open angle bracket.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident < $($rest:tt)* }]
+        _tokens = [{ $a:tt $lt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ < $($stack)* }]
+            type = [{ $($ty)* $a $lt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by a comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident , $($rest:tt)* }]
+        _tokens = [{ $a:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $a $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by a close angle bracket and
+    // comma.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < $top:tt $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident > , $($rest:tt)* }]
+        _tokens = [{ $a:tt $gt:tt $comma:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ type start }]
+            stack = [{ $top $($stack)* }]
+            type = [{ $($ty)* $a $gt $comma }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by a close angle bracket.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident > $($rest:tt)* }]
+        _tokens = [{ $a:tt $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by two close angle brackets.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ < < $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident >> $($rest:tt)* }]
+        _tokens = [{ $a:tt $gt:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after angle }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a $gt }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by a trailing comma at the end of
+    // parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ $_a:ident , }]
+        _tokens = [{ $($_inner_dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment at the end of parentheses.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{
+            [{
+                $group:ident
+                type = [{ $($outer:tt)* }]
+                tokens = [{ $($rest:tt)* }]
+                _tokens = [{ $($dup:tt)* }]
+            }]
+            $($stack:tt)*
+        }]
+        type = [{ $($inner:tt)* }]
+        tokens = [{ $_a:ident }]
+        _tokens = [{ $($_inner_dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after $group }]
+            stack = [{ $($stack)* }]
+            type = [{ $($outer)* }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Path of one segment followed by something else. Look at the next
+    // token in the next step.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $_a:ident $($rest:tt)* }]
+        _tokens = [{ $a:tt $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type_fast! {
+            $caller
+            input = [{ $($input)* }]
+            step = [{ after path }]
+            stack = [{ $($stack)* }]
+            type = [{ $($ty)* $a }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
+        }
+    };
+
+    // Anything else. Let the full parser handle it.
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+        step = [{ $($step:tt)* }]
+        stack = [{ $($stack:tt)* }]
+        type = [{ $($ty:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        _tokens = [{ $($dup:tt)* }]
+    } => {
+        $crate::private_parse_type! {
             $caller
-            pieces = [{ }]
             tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
         }
     };
+}
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_parse_type_with_plus {
     // There is at least one previously parsed piece, and next token is a
     // lifetime.
     {
@@ -83,8 +1487,9 @@ macro_rules! private_parse_type_with_plus {
         tokens = [{ $($tokens:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($tokens)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($tokens)* }]
+            _tokens = [{ $($tokens)* }]
             ~~> $crate::private_parse_type_with_plus! {
                 $caller
                 pieces = [{ $($pieces)* }]
@@ -131,7 +1536,7 @@ macro_rules! private_parse_type_with_plus {
         rest = [{ $($rest:tt)* }]
         _rest = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($pieces)* $($ty)* }]
             rest = [{ $($rest)* }]
@@ -153,7 +1558,7 @@ macro_rules! private_parse_type {
             macro = [{ $crate::private_validate_inside_brackets }]
             original = [{ $original }]
             input = [{ $($bracketed)* }]
-            ~~> $crate::tt_return! {
+            ~~> $crate::private_return! {
                 $caller
                 type = [{ $original }]
                 rest = [{ $($rest)* }]
@@ -181,7 +1586,7 @@ macro_rules! private_parse_type {
         $crate::tt_call! {
             macro = [{ $crate::private_validate_inside_parens }]
             input = [{ $($parenthesized)* }]
-            ~~> $crate::tt_return! {
+            ~~> $crate::private_return! {
                 $caller
                 type = [{ $original }]
                 rest = [{ $($rest)* }]
@@ -245,7 +1650,7 @@ macro_rules! private_parse_type {
         tokens = [{ ! $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ ! }]
             rest = [{ $($rest)* }]
@@ -260,7 +1665,8 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_type_with_plus }]
-            input = [{ $($rest)* }]
+            pieces = [{ }]
+            tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_type! {
                 $caller
                 object = [{ $dyn }]
@@ -287,7 +1693,8 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_type_with_plus }]
-            input = [{ $($rest)* }]
+            pieces = [{ }]
+            tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_type! {
                 $caller
                 object = [{ $impl }]
@@ -313,7 +1720,7 @@ macro_rules! private_parse_type {
         type = [{ $($element:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $kind $($element)* }]
             rest = [{ $($rest)* }]
@@ -326,7 +1733,7 @@ macro_rules! private_parse_type {
         tokens = [{ _ $($rest:tt)* }]
         _tokens = [{ $underscore:tt $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $underscore }]
             rest = [{ $($rest)* }]
@@ -365,7 +1772,7 @@ macro_rules! private_parse_type {
         tokens = [{ $($path:ident)::+ ! ( $($args:tt)* ) $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($path)::* ! ( $($args)* ) }]
             rest = [{ $($rest)* }]
@@ -378,7 +1785,7 @@ macro_rules! private_parse_type {
         tokens = [{ $(:: $path:ident)+ ! ( $($args:tt)* ) $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $(:: $path)* ! ( $($args)* ) }]
             rest = [{ $($rest)* }]
@@ -391,7 +1798,7 @@ macro_rules! private_parse_type {
         tokens = [{ $($path:ident)::+ ! [ $($args:tt)* ] $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($path)::* ! [ $($args)* ] }]
             rest = [{ $($rest)* }]
@@ -404,7 +1811,7 @@ macro_rules! private_parse_type {
         tokens = [{ $(:: $path:ident)+ ! [ $($args:tt)* ] $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $(:: $path)* ! [ $($args)* ] }]
             rest = [{ $($rest)* }]
@@ -417,7 +1824,7 @@ macro_rules! private_parse_type {
         tokens = [{ $($path:ident)+ ! { $($args:tt)* } $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($path)::* ! { $($args)* } }]
             rest = [{ $($rest)* }]
@@ -430,7 +1837,7 @@ macro_rules! private_parse_type {
         tokens = [{ $(:: $path:ident)+ ! { $($args:tt)* } $($rest:tt)* }]
         _tokens = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $(:: $path)* ! { $($args)* } }]
             rest = [{ $($rest)* }]
@@ -444,8 +1851,9 @@ macro_rules! private_parse_type {
         _tokens = [{ $lt:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_type! {
                 $caller
                 type_prefix = [{ $lt }]
@@ -492,10 +1900,12 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
-            input = [{ $($rest)* }]
+            path = [{ $colons $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
-                qpath = [{ $lt $($qself)* $as $colons $segment }]
+                qpath = [{ $lt $($qself)* $as }]
             }
         }
     };
@@ -523,10 +1933,12 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
-            input = [{ $($rest)* }]
+            path = [{ $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
-                qpath = [{ $lt $($qself)* $as $segment }]
+                qpath = [{ $lt $($qself)* $as }]
             }
         }
     };
@@ -570,10 +1982,12 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
-            input = [{ $($rest)* }]
+            path = [{ $colons $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
-                path_prefix = [{ $($qpath)* $($path)* $gt $colons $segment }]
+                path_prefix = [{ $($qpath)* $($path)* $gt }]
             }
         }
     };
@@ -641,10 +2055,12 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
-            input = [{ $($rest)* }]
+            path = [{ $colons $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
-                path_prefix = [{ $lt $($qself)* $gt $colons $segment }]
+                path_prefix = [{ $lt $($qself)* $gt }]
             }
         }
     };
@@ -669,8 +2085,10 @@ macro_rules! private_parse_type {
         _tokens = [{ $colons:tt $segment:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::private_parse_path }]
-            input = [{ $colons $segment $($rest)* }]
+            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
+            path = [{ $colons $segment }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
                 path_prefix = [{ }]
@@ -689,15 +2107,53 @@ macro_rules! private_parse_type {
         }
     };
 
-    // Parse relative path.
+    // Parse relative path of more than one segment.
     {
         $caller:tt
-        tokens = [{ $_segment:ident $($rest:tt)* }]
-        _tokens = [{ $segment:tt $($dup:tt)* }]
+        tokens = [{ $_segment:ident :: $($rest:tt)* }]
+        _tokens = [{ $segment:tt $next:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::private_parse_path }]
-            input = [{ $segment $($rest)* }]
+            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
+            path = [{ $segment }]
+            tokens = [{ $next $($rest)* }]
+            _tokens = [{ $next $($dup)* }]
+            ~~> $crate::private_parse_type! {
+                $caller
+                path_prefix = [{ }]
+            }
+        }
+    };
+
+    // Parse relative path with angle bracketed parameters.
+    {
+        $caller:tt
+        tokens = [{ $_segment:ident < $($rest:tt)* }]
+        _tokens = [{ $segment:tt $next:tt $($dup:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
+            path = [{ $segment }]
+            tokens = [{ $next $($rest)* }]
+            _tokens = [{ $next $($dup)* }]
+            ~~> $crate::private_parse_type! {
+                $caller
+                path_prefix = [{ }]
+            }
+        }
+    };
+
+    // Parse relative path with parenthesized parameters.
+    {
+        $caller:tt
+        tokens = [{ $_segment:ident ($($_args:tt)*) $($rest:tt)* }]
+        _tokens = [{ $segment:tt $next:tt $($dup:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_parse_possibly_empty_path_after_ident }]
+            path = [{ $segment }]
+            tokens = [{ $next $($rest)* }]
+            _tokens = [{ $next $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
                 path_prefix = [{ }]
@@ -705,6 +2161,19 @@ macro_rules! private_parse_type {
         }
     };
 
+    // Relative path is a single identifier. Return.
+    {
+        $caller:tt
+        tokens = [{ $_segment:ident $($rest:tt)* }]
+        _tokens = [{ $segment:tt $($dup:tt)* }]
+    } => {
+        $crate::private_return! {
+            $caller
+            type = [{ $segment }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
     // First token is `?` for a maybe-trait.
     {
         $caller:tt
@@ -713,7 +2182,8 @@ macro_rules! private_parse_type {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_path }]
-            input = [{ $($tokens)* }]
+            tokens = [{ $($tokens)* }]
+            _tokens = [{ $($dup)* }]
             ~~> $crate::private_parse_type! {
                 $caller
                 path_prefix = [{ $question }]
@@ -728,7 +2198,7 @@ macro_rules! private_parse_type {
         path = [{ $($path:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($prefix)* $($path)* }]
             rest = [{ $($rest)* }]
@@ -781,8 +2251,9 @@ macro_rules! private_parse_pointer {
         _tokens = [{ $const:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_pointer! {
                 $caller
                 pointer = [{ $asterisk $const }]
@@ -798,8 +2269,9 @@ macro_rules! private_parse_pointer {
         _tokens = [{ $mut:tt $($dup:tt)+ }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_pointer! {
                 $caller
                 pointer = [{ $asterisk $mut }]
@@ -826,7 +2298,7 @@ macro_rules! private_parse_pointer {
         type = [{ $($element:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($pointer)* $($element)* }]
             rest = [{ $($rest)* }]
@@ -901,8 +2373,9 @@ macro_rules! private_parse_reference {
         _tokens = [{ $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_reference! {
                 $caller
                 reference = [{ $ampersand $($lifetime)* $($mut)* }]
@@ -917,7 +2390,7 @@ macro_rules! private_parse_reference {
         type = [{ $($element:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($reference)* $($element)* }]
             rest = [{ $($rest)* }]
@@ -993,8 +2466,9 @@ macro_rules! private_parse_function {
         _rest = [{ $arrow:tt $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_function! {
                 $caller
                 function = [{ $fn $args $arrow }]
@@ -1009,7 +2483,7 @@ macro_rules! private_parse_function {
         rest = [{ $($rest:tt)* }]
         _rest = [{ $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $fn $args }]
             rest = [{ $($rest)* }]
@@ -1023,7 +2497,7 @@ macro_rules! private_parse_function {
         type = [{ $($ret:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $fn $args $arrow $($ret)* }]
             rest = [{ $($rest)* }]
@@ -1085,8 +2559,9 @@ macro_rules! private_parse_poly_trait {
         rest = [{ $gt:tt $($rest:tt)+ }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_parse_poly_trait! {
                 $caller
                 poly_trait = [{ $for $lt $($params)* $gt }]
@@ -1113,7 +2588,7 @@ macro_rules! private_parse_poly_trait {
         type = [{ $($ty:tt)* }]
         rest = [{ $($rest:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             type = [{ $($params)* $($ty)* }]
             rest = [{ $($rest)* }]
@@ -1146,7 +2621,7 @@ macro_rules! private_parse_lifetime_params {
         tokens = [{ > $($rest:tt)* }]
         _tokens = [{ $gt:tt $($dup:tt)* }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
             lifetime_params = [{ $($params)* }]
             rest = [{ $gt $($rest)* }]
@@ -1246,8 +2721,9 @@ macro_rules! private_validate_inside_brackets {
         input = [{ $($input:tt)+ }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($input)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($input)* }]
+            _tokens = [{ $($input)* }]
             ~~> $crate::private_validate_inside_brackets! {
                 $caller
                 original = [{ $original }]
@@ -1262,7 +2738,7 @@ macro_rules! private_validate_inside_brackets {
         type = [{ $($bracketed:tt)* }]
         rest = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -1313,7 +2789,7 @@ macro_rules! private_validate_inside_brackets {
         expr = [{ $($len:tt)* }]
         rest = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -1338,7 +2814,7 @@ macro_rules! private_validate_inside_parens {
         $caller:tt
         input = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -1350,7 +2826,8 @@ macro_rules! private_validate_inside_parens {
     } => {
         $crate::tt_call! {
             macro = [{ $crate::private_parse_type_with_plus }]
-            input = [{ $($tokens)* }]
+            pieces = [{ }]
+            tokens = [{ $($tokens)* }]
             ~~> $crate::private_validate_inside_parens! {
                 $caller
             }
@@ -1363,7 +2840,7 @@ macro_rules! private_validate_inside_parens {
         type = [{ $($element:tt)* }]
         rest = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -1374,7 +2851,7 @@ macro_rules! private_validate_inside_parens {
         type = [{ $($element:tt)* }]
         rest = [{ , }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -1386,8 +2863,9 @@ macro_rules! private_validate_inside_parens {
         rest = [{ , $($rest:tt)+ }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_validate_inside_parens! {
                 $caller
             }
@@ -1427,7 +2905,7 @@ macro_rules! private_validate_fn_args {
         tokens = [{ }]
         _tokens = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -1439,8 +2917,9 @@ macro_rules! private_validate_fn_args {
         _tokens = [{ $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_validate_fn_args! {
                 $caller
             }
@@ -1465,8 +2944,9 @@ macro_rules! private_validate_fn_args {
         _tokens = [{ $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_validate_fn_args! {
                 $caller
             }
@@ -1491,8 +2971,9 @@ macro_rules! private_validate_fn_args {
         _tokens = [{ $($dup:tt)* }]
     } => {
         $crate::tt_call! {
-            macro = [{ $crate::parse_type }]
-            input = [{ $($rest)* }]
+            macro = [{ $crate::private_parse_type }]
+            tokens = [{ $($rest)* }]
+            _tokens = [{ $($rest)* }]
             ~~> $crate::private_validate_fn_args! {
                 $caller
             }
@@ -1505,7 +2986,7 @@ macro_rules! private_validate_fn_args {
         type = [{ $($ty:tt)* }]
         rest = [{ }]
     } => {
-        $crate::tt_return! {
+        $crate::private_return! {
             $caller
         }
     };
@@ -0,0 +1,189 @@
+/// Run a sequence of parsers one after another, each one continuing from where
+/// the previous one left off.
+/// <sup>**[tt-call]**</sup>
+///
+/// Each step names a parser macro. The first parser is invoked on the entire
+/// input, and every following parser is invoked on the `rest` returned by the
+/// one before it. The outputs of each step other than `rest` are returned
+/// grouped under the name of the step.
+///
+/// # Input
+///
+///   - **`$(`**<br>
+///     &emsp;&emsp;`step = [{` name of step `=>` name of parser macro `}]`<br>
+///     **`)*`**
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The parser macros must accept a single input value named `input`. They are
+/// expected to return any number of output values followed last by `rest`,
+/// the same as [`parse_type!`].
+///
+/// [`parse_type!`]: macro.parse_type.html
+///
+/// # Output
+///
+///   - **`$(`**<br>
+///     &emsp;&emsp;name of step `= [{` outputs of step other than `rest`
+///     `}]`<br>
+///     **`)*`**
+///   - `rest = [{` remaining tokens after the last step `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{parse_type, tt_call, tt_return, tt_sequence};
+///
+/// macro_rules! parse_ident {
+///     {
+///         $caller:tt
+///         input = [{ $ident:ident $($rest:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             ident = [{ $ident }]
+///             rest = [{ $($rest)* }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! parse_colon {
+///     {
+///         $caller:tt
+///         input = [{ : $($rest:tt)* }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             rest = [{ $($rest)* }]
+///         }
+///     };
+/// }
+///
+/// macro_rules! typed_let {
+///     ($($input:tt)*) => {
+///         tt_call! {
+///             macro = [{ tt_sequence }]
+///             step = [{ name => parse_ident }]
+///             step = [{ colon => parse_colon }]
+///             step = [{ ty => parse_type }]
+///             input = [{ $($input)* }]
+///             ~~> typed_let_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! typed_let_return {
+///     {
+///         name = [{ ident = [{ $name:ident }] }]
+///         colon = [{ }]
+///         ty = [{ type = [{ $($ty:tt)* }] }]
+///         rest = [{ = $value:expr }]
+///     } => {
+///         let $name: $($ty)* = $value;
+///     };
+/// }
+///
+/// fn main() {
+///     typed_let!(v: Vec<u8> = Vec::new());
+///     assert!(v.is_empty());
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_sequence {
+    {
+        $caller:tt
+        $(
+            step = [{ $name:ident => $($parser:ident)::* }]
+        )*
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_sequence! {
+            $caller
+            steps = [{ $( [ $name => $($parser)::* ] )* }]
+            outputs = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_sequence {
+    // All steps are done. Return to caller.
+    {
+        $caller:tt
+        steps = [{ }]
+        outputs = [{ $($outputs:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            $($outputs)*
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Invoke the parser of the next step on the remaining tokens.
+    {
+        $caller:tt
+        steps = [{ [ $name:ident => $($parser:ident)::* ] $($steps:tt)* }]
+        outputs = [{ $($outputs:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($parser)::* }]
+            input = [{ $($rest)* }]
+            ~~> $crate::private_sequence! {
+                $caller
+                steps = [{ $($steps)* }]
+                outputs = [{ $($outputs)* }]
+                step = [{ $name }]
+            }
+        }
+    };
+
+    // Return from parser. Only `rest` remains, so the step is done.
+    {
+        $caller:tt
+        steps = [{ $($steps:tt)* }]
+        outputs = [{ $($outputs:tt)* }]
+        step = [{ $name:ident $($step:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::private_sequence! {
+            $caller
+            steps = [{ $($steps)* }]
+            outputs = [{ $($outputs)* $name = [{ $($step)* }] }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Return from parser. Collect one output value under the name of the step.
+    {
+        $caller:tt
+        steps = [{ $($steps:tt)* }]
+        outputs = [{ $($outputs:tt)* }]
+        step = [{ $name:ident $($step:tt)* }]
+        $output:ident = [{ $($tokens:tt)* }]
+        $($returned:tt)*
+    } => {
+        $crate::private_sequence! {
+            $caller
+            steps = [{ $($steps)* }]
+            outputs = [{ $($outputs)* }]
+            step = [{ $name $($step)* $output = [{ $($tokens)* }] }]
+            $($returned)*
+        }
+    };
+
+    // Unexpected: parser returned without `rest`.
+    {
+        $caller:tt
+        steps = [{ $($steps:tt)* }]
+        outputs = [{ $($outputs:tt)* }]
+        step = [{ $name:ident $($step:tt)* }]
+    } => {
+        $crate::error_unexpected! {
+            $name
+        }
+    };
+}
@@ -0,0 +1,511 @@
+/// Define the entry point of a tt-call macro that accepts its inputs in any
+/// order, with default values for the optional ones.
+///
+/// The calling convention passes inputs as `key = [{ ... }]` pairs, and a
+/// macro whose rules match those pairs literally only works if the caller
+/// writes them in exactly the order of the rules. `tt_signature!` declares the
+/// inputs of a macro once and generates a macro that takes them in any order,
+/// fills in defaults for optional inputs that were not given, and then invokes
+/// the macro that does the actual work with every input in the declared order.
+///
+/// ```text
+/// tt_signature! {
+///     $(#[$attr])*
+///     macro_rules! $name {
+///         $(
+///             $input
+///             $( = [{ default value }] )?
+///         ),*
+///     } => $target
+/// }
+/// ```
+///
+/// An input written without a default value is required. An input with a
+/// default value is optional. The generated macro `$name` invokes `$target`
+/// with the caller followed by one `$input = [{ ... }]` pair per declared
+/// input.
+///
+/// The generated macro fails to compile with an error whose message names the
+/// offending input if it is given an input that was not declared, the same
+/// input more than once, or no value for a required input. The error is
+/// reported on the whole call rather than on the offending key. An input
+/// declared with more than one default value is an error in the
+/// `tt_signature!` itself.
+///
+/// The target is a path, which may be absolute like `::other_crate::target`.
+/// If the generated macro is `#[macro_export]`, write the target as
+/// `$crate::path::to::target` so that it can be found from other crates.
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_return, tt_signature};
+///
+/// tt_signature! {
+///     macro_rules! greet {
+///         name,
+///         greeting = [{ "Hello" }],
+///     } => private_greet
+/// }
+///
+/// macro_rules! private_greet {
+///     {
+///         $caller:tt
+///         name = [{ $name:expr }]
+///         greeting = [{ $greeting:expr }]
+///     } => {
+///         tt_return! {
+///             $caller
+///             message = [{ format!("{}, {}!", $greeting, $name) }]
+///         }
+///     };
+/// }
+///
+/// fn main() {
+///     let default = tt_call! {
+///         macro = [{ greet }]
+///         name = [{ "world" }]
+///     };
+///     assert_eq!(default, "Hello, world!");
+///
+///     let reordered = tt_call! {
+///         macro = [{ greet }]
+///         greeting = [{ "Hi" }]
+///         name = [{ "there" }]
+///     };
+///     assert_eq!(reordered, "Hi, there!");
+/// }
+/// ```
+///
+/// Giving an input that was not declared is an error.
+///
+/// ```text
+/// error: unexpected input `punctuation`
+///   --> src/main.rs:23:19
+///    |
+/// 23 |       let message = tt_call! {
+///    |  ___________________^
+/// 24 | |         macro = [{ greet }]
+/// 25 | |         name = [{ "world" }]
+/// 26 | |         punctuation = [{ "?" }]
+/// 27 | |     };
+///    | |_____^
+/// ```
+#[macro_export]
+macro_rules! tt_signature {
+    {
+        $(#[$attr:meta])*
+        macro_rules! $name:ident {
+            $(
+                $input:ident $( = [{ $($default:tt)* }] )*
+            ),* $(,)*
+        } => $($target:tt)+
+    } => {
+        $crate::private_signature_target! {
+            ($)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            target = [{ $($target)+ }]
+            slots = [{ $( [$input ( $( [{ $($default)* }] )* )] )* }]
+            inputs = [{ $($input)* }]
+        }
+    };
+}
+
+// The `crate` in the first rule matches the target as the caller wrote it,
+// rather than referring to any crate.
+#[allow(clippy::crate_in_macro_def)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_signature_target {
+    // Target is relative to `$crate`. The generated macro is then presumably
+    // exported, so it refers to itself through `$crate` too.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        target = [{ $dollar:tt crate :: $($target:ident)::+ }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+    } => {
+        $crate::private_signature_check! {
+            ($d)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            path = [{ $dollar crate :: $name }]
+            target = [{ $dollar crate :: $($target)::+ }]
+            slots = [{ $($slots)* }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($slots)* }]
+        }
+    };
+
+    // Target is an absolute path.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        target = [{ :: $($target:ident)::+ }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+    } => {
+        $crate::private_signature_check! {
+            ($d)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            path = [{ $name }]
+            target = [{ :: $($target)::+ }]
+            slots = [{ $($slots)* }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($slots)* }]
+        }
+    };
+
+    // Target is a relative path of one or more segments.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        target = [{ $($target:ident)::+ }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+    } => {
+        $crate::private_signature_check! {
+            ($d)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            path = [{ $name }]
+            target = [{ $($target)::+ }]
+            slots = [{ $($slots)* }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($slots)* }]
+        }
+    };
+
+    // Unexpected: target is not a path.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        target = [{ $($target:tt)* }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+    } => {
+        compile_error! {
+            concat!("expected a path to the target macro, found `", stringify!($($target)*), "`")
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_signature_check {
+    // Next input is required. Check the rest.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        path = [{ $($path:tt)* }]
+        target = [{ $($target:tt)* }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ [$input:ident ()] $($rest:tt)* }]
+    } => {
+        $crate::private_signature_check! {
+            ($d)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            path = [{ $($path)* }]
+            target = [{ $($target)* }]
+            slots = [{ $($slots)* }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Next input has one default value. Check the rest.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        path = [{ $($path:tt)* }]
+        target = [{ $($target:tt)* }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ [$input:ident ([{ $($default:tt)* }])] $($rest:tt)* }]
+    } => {
+        $crate::private_signature_check! {
+            ($d)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            path = [{ $($path)* }]
+            target = [{ $($target)* }]
+            slots = [{ $($slots)* }]
+            inputs = [{ $($inputs)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Unexpected: input has more than one default value.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        path = [{ $($path:tt)* }]
+        target = [{ $($target:tt)* }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ [$input:ident ($first:tt $($more:tt)+)] $($rest:tt)* }]
+    } => {
+        compile_error! {
+            concat!("input `", stringify!($input), "` has more than one default value")
+        }
+    };
+
+    // Every input has at most one default value. Define the macro.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        path = [{ $($path:tt)* }]
+        target = [{ $($target:tt)* }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::private_signature_define! {
+            ($d)
+            attrs = [{ $(#[$attr])* }]
+            name = [{ $name }]
+            path = [{ $($path)* }]
+            target = [{ $($target)* }]
+            slots = [{ $($slots)* }]
+            inputs = [{ $($inputs)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_signature_define {
+    // Define a macro with one rule per declared input that puts the input in
+    // its slot. Slots are written `[input ()]` for a required input that has
+    // not been given yet, `[input ([{ default }])]` for an optional one, and
+    // `[input = [{ value }]]` once given. See `private_eq!` for the use of
+    // `$d`.
+    {
+        ($d:tt)
+        attrs = [{ $(#[$attr:meta])* }]
+        name = [{ $name:ident }]
+        path = [{ $($path:tt)* }]
+        target = [{ $($target:tt)* }]
+        slots = [{ $($slots:tt)* }]
+        inputs = [{ $($input:ident)* }]
+    } => {
+        $(#[$attr])*
+        macro_rules! $name {
+            // Next input. Look for its slot.
+            {
+                __tt_signature
+                $d __tt_caller:tt
+                path = [{ $d($d __tt_path:tt)* }]
+                slots = [{ $d($d __tt_slots:tt)* }]
+                rest = [{ $d __tt_key:ident = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+            } => {
+                $d($d __tt_path)* ! {
+                    __tt_signature
+                    $d __tt_caller
+                    path = [{ $d($d __tt_path)* }]
+                    key = [{ $d __tt_key }]
+                    value = [{ $d($d __tt_value)* }]
+                    before = [{ }]
+                    after = [{ $d($d __tt_slots)* }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+
+            $(
+                // Found the slot of this input. Fill it in.
+                {
+                    __tt_signature
+                    $d __tt_caller:tt
+                    path = [{ $d($d __tt_path:tt)* }]
+                    key = [{ $input }]
+                    value = [{ $d($d __tt_value:tt)* }]
+                    before = [{ $d($d __tt_before:tt)* }]
+                    after = [{ [$input ( $d($d __tt_default:tt)* )] $d($d __tt_after:tt)* }]
+                    rest = [{ $d($d __tt_rest:tt)* }]
+                } => {
+                    $d($d __tt_path)* ! {
+                        __tt_signature
+                        $d __tt_caller
+                        path = [{ $d($d __tt_path)* }]
+                        slots = [{
+                            $d($d __tt_before)*
+                            [$input = [{ $d($d __tt_value)* }]]
+                            $d($d __tt_after)*
+                        }]
+                        rest = [{ $d($d __tt_rest)* }]
+                    }
+                };
+
+                // Unexpected: this input was already given.
+                {
+                    __tt_signature
+                    $d __tt_caller:tt
+                    path = [{ $d($d __tt_path:tt)* }]
+                    key = [{ $input }]
+                    value = [{ $d($d __tt_value:tt)* }]
+                    before = [{ $d($d __tt_before:tt)* }]
+                    after = [{ [$input = $d($d __tt_given:tt)*] $d($d __tt_after:tt)* }]
+                    rest = [{ $d($d __tt_rest:tt)* }]
+                } => {
+                    compile_error! {
+                        concat!("input `", stringify!($input), "` is given more than once")
+                    }
+                };
+            )*
+
+            // Next slot belongs to a different input. Keep looking.
+            {
+                __tt_signature
+                $d __tt_caller:tt
+                path = [{ $d($d __tt_path:tt)* }]
+                key = [{ $d __tt_key:ident }]
+                value = [{ $d($d __tt_value:tt)* }]
+                before = [{ $d($d __tt_before:tt)* }]
+                after = [{ $d __tt_slot:tt $d($d __tt_after:tt)* }]
+                rest = [{ $d($d __tt_rest:tt)* }]
+            } => {
+                $d($d __tt_path)* ! {
+                    __tt_signature
+                    $d __tt_caller
+                    path = [{ $d($d __tt_path)* }]
+                    key = [{ $d __tt_key }]
+                    value = [{ $d($d __tt_value)* }]
+                    before = [{ $d($d __tt_before)* $d __tt_slot }]
+                    after = [{ $d($d __tt_after)* }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+
+            // Unexpected: there is no input by this name.
+            {
+                __tt_signature
+                $d __tt_caller:tt
+                path = [{ $d($d __tt_path:tt)* }]
+                key = [{ $d __tt_key:ident }]
+                value = [{ $d($d __tt_value:tt)* }]
+                before = [{ $d($d __tt_before:tt)* }]
+                after = [{ }]
+                rest = [{ $d($d __tt_rest:tt)* }]
+            } => {
+                compile_error! {
+                    concat!("unexpected input `", stringify!($d __tt_key), "`")
+                }
+            };
+
+            // Arrived at end of inputs. Fill in defaults and invoke target.
+            {
+                __tt_signature
+                $d __tt_caller:tt
+                path = [{ $d($d __tt_path:tt)* }]
+                slots = [{ $d($d __tt_slots:tt)* }]
+                rest = [{ }]
+            } => {
+                $crate::private_signature! {
+                    $d __tt_caller
+                    target = [{ $($target)* }]
+                    inputs = [{ }]
+                    slots = [{ $d($d __tt_slots)* }]
+                }
+            };
+
+            // Unexpected: not in the form of an input.
+            {
+                __tt_signature
+                $d __tt_caller:tt
+                path = [{ $d($d __tt_path:tt)* }]
+                slots = [{ $d($d __tt_slots:tt)* }]
+                rest = [{ $d($d __tt_unexpected:tt)+ }]
+            } => {
+                $crate::error_unexpected! {
+                    $d($d __tt_unexpected)*
+                }
+            };
+
+            // Entry point.
+            {
+                $d __tt_caller:tt
+                $d($d __tt_inputs:tt)*
+            } => {
+                $($path)* ! {
+                    __tt_signature
+                    $d __tt_caller
+                    path = [{ $($path)* }]
+                    slots = [{ $($slots)* }]
+                    rest = [{ $d($d __tt_inputs)* }]
+                }
+            };
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_signature {
+    // Input was given.
+    {
+        $caller:tt
+        target = [{ $($target:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        slots = [{ [$input:ident = [{ $($value:tt)* }]] $($slots:tt)* }]
+    } => {
+        $crate::private_signature! {
+            $caller
+            target = [{ $($target)* }]
+            inputs = [{ $($inputs)* $input = [{ $($value)* }] }]
+            slots = [{ $($slots)* }]
+        }
+    };
+
+    // Optional input was not given. Use its default.
+    {
+        $caller:tt
+        target = [{ $($target:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        slots = [{ [$input:ident ([{ $($default:tt)* }])] $($slots:tt)* }]
+    } => {
+        $crate::private_signature! {
+            $caller
+            target = [{ $($target)* }]
+            inputs = [{ $($inputs)* $input = [{ $($default)* }] }]
+            slots = [{ $($slots)* }]
+        }
+    };
+
+    // Unexpected: required input was not given.
+    {
+        $caller:tt
+        target = [{ $($target:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        slots = [{ [$input:ident ()] $($slots:tt)* }]
+    } => {
+        compile_error! {
+            concat!("missing required input `", stringify!($input), "`")
+        }
+    };
+
+    // All inputs are in place. Invoke target.
+    {
+        $caller:tt
+        target = [{ $($target:tt)* }]
+        inputs = [{ $($inputs:tt)* }]
+        slots = [{ }]
+    } => {
+        $($target)* ! {
+            $caller
+            $($inputs)*
+        }
+    };
+}
@@ -0,0 +1,169 @@
+/// Split a token stream into segments wherever a given predicate matches.
+/// <sup>**[tt-call]**</sup>
+///
+/// The predicate is applied to each top-level token tree of the input. Groups
+/// are never split; a separator inside of parentheses, square brackets or
+/// curly braces belongs to the segment containing the group. The separators
+/// themselves are not included in any segment.
+///
+/// Every separator terminates the segment before it, even if that segment is
+/// empty. The tokens after the last separator form one more segment only if
+/// there are any, so a trailing separator does not produce an empty segment at
+/// the end and an empty input produces no segments at all.
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+/// For example the built-in `tt_is_comma!` predicate expands to
+/// `is_comma = [{ true }]` or `is_comma = [{ false }]`.
+///
+/// # Output
+///
+///   - **`$(`**<br>
+///     &emsp;&emsp;`segment = [{` tokens between separators `}]`<br>
+///     **`)*`**
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_is_comma, tt_split};
+///
+/// macro_rules! array {
+///     ($($input:tt)*) => {
+///         tt_call! {
+///             macro = [{ tt_split }]
+///             condition = [{ tt_is_comma }]
+///             input = [{ $($input)* }]
+///             ~~> array_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! array_return {
+///     {
+///         $(
+///             segment = [{ $($element:tt)* }]
+///         )*
+///     } => {
+///         [ $( $($element)* ),* ]
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(array!(1 + 2, f(3, 4), 4 * 5,), [3, 7, 20]);
+/// }
+/// #
+/// # fn f(a: i32, b: i32) -> i32 {
+/// #     a + b
+/// # }
+/// ```
+#[macro_export]
+macro_rules! tt_split {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_split! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            segments = [{ }]
+            current = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_split! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_split {
+    // Arrived at end of input with no tokens after the last separator. Return
+    // to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        segments = [{ $($segments:tt)* }]
+        current = [{ }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            $($segments)*
+        }
+    };
+
+    // Arrived at end of input. Return to caller including the final segment.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        segments = [{ $($segments:tt)* }]
+        current = [{ $($current:tt)+ }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            $($segments)*
+            segment = [{ $($current)* }]
+        }
+    };
+
+    // Invoke condition on the next token tree to see whether it is a
+    // separator.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        segments = [{ $($segments:tt)* }]
+        current = [{ $($current:tt)* }]
+        rest = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::private_if! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            input = [{ $first }]
+            true = [{
+                $crate::private_split! {
+                    condition = [{ $($condition)::* }]
+                    condition_args = [{ $($condition_args)* }]
+                    segments = [{ $($segments)* segment = [{ $($current)* }] }]
+                    current = [{ }]
+                    rest = [{ $($rest)* }]
+                }
+            }]
+            false = [{
+                $crate::private_split! {
+                    condition = [{ $($condition)::* }]
+                    condition_args = [{ $($condition_args)* }]
+                    segments = [{ $($segments)* }]
+                    current = [{ $($current)* $first }]
+                    rest = [{ $($rest)* }]
+                }
+            }]
+        }
+    };
+}
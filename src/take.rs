@@ -0,0 +1,270 @@
+/// Take the longest prefix of token trees that all match a given predicate.
+/// <sup>**[tt-call]**</sup>
+///
+/// The predicate is applied to each top-level token tree of the input in order
+/// until it first returns false. Groups are tested as a whole without looking
+/// inside of them.
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+/// For example the built-in `tt_is_ident!` predicate expands to
+/// `is_ident = [{ true }]` or `is_ident = [{ false }]`.
+///
+/// # Output
+///
+///   - `prefix = [{` token trees before the first one that does not match `}]`
+///   - `rest = [{` remaining tokens starting at the first one that does not
+///     match `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_debug, tt_is_ident, tt_take_while};
+///
+/// fn main() {
+///     tt_call! {
+///         macro = [{ tt_take_while }]
+///         condition = [{ tt_is_ident }]
+///         input = [{ pub unsafe fn f() {} }]
+///         ~~> tt_debug
+///     }
+/// }
+/// ```
+///
+/// The output is:
+///
+/// ```text
+/// prefix = [{ pub unsafe fn f }]
+/// rest = [{ () {} }]
+/// ```
+#[macro_export]
+macro_rules! tt_take_while {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_take! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            take = [{ true }]
+            prefix = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_take_while! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+/// Take the longest prefix of token trees up to the first one that matches a
+/// given predicate.
+/// <sup>**[tt-call]**</sup>
+///
+/// The predicate is applied to each top-level token tree of the input in order
+/// until it first returns true. Groups are tested as a whole without looking
+/// inside of them.
+///
+/// # Input
+///
+///   - `condition = [{` name of predicate macro `}]`
+///   - `condition_args = [{` inputs to pass to the predicate before `input`
+///     `}]` &emsp;&emsp;(optional)
+///   - `input = [{` arbitrary input tokens `}]`
+///
+/// The predicate macro must accept an input value named `input`, after any
+/// inputs given in `condition_args`. It is expected to return a single output
+/// value which may have any name but must hold the tokens `true` or `false`.
+///
+/// # Output
+///
+///   - `prefix = [{` token trees before the first one that matches `}]`
+///   - `rest = [{` remaining tokens starting at the first one that matches, or
+///     nothing if none matches `}]`
+///
+/// # Example
+///
+/// ```
+/// use tt_call::{tt_call, tt_is_comma, tt_take_until};
+///
+/// macro_rules! first {
+///     ($($input:tt)*) => {
+///         tt_call! {
+///             macro = [{ tt_take_until }]
+///             condition = [{ tt_is_comma }]
+///             input = [{ $($input)* }]
+///             ~~> first_return
+///         }
+///     };
+/// }
+///
+/// macro_rules! first_return {
+///     {
+///         prefix = [{ $($first:tt)* }]
+///         rest = [{ $($rest:tt)* }]
+///     } => {
+///         $($first)*
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(first!(1 + 2, 3, 4), 3);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_take_until {
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::private_take! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            take = [{ false }]
+            prefix = [{ }]
+            rest = [{ $($input)* }]
+        }
+    };
+
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        input = [{ $($input:tt)* }]
+    } => {
+        $crate::tt_take_until! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ }]
+            input = [{ $($input)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_take {
+    // Arrived at end of input. Return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        take = [{ $take:ident }]
+        prefix = [{ $($prefix:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            prefix = [{ $($prefix)* }]
+            rest = [{ }]
+        }
+    };
+
+    // Invoke condition on the next token tree.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        take = [{ $take:ident }]
+        prefix = [{ $($prefix:tt)* }]
+        rest = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $($condition)::* }]
+            $($condition_args)*
+            input = [{ $first }]
+            ~~> $crate::private_take! {
+                $caller
+                condition = [{ $($condition)::* }]
+                condition_args = [{ $($condition_args)* }]
+                take = [{ $take }]
+                prefix = [{ $($prefix)* }]
+                first = [{ $first }]
+                after_first = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Condition returned true while taking tokens that match. Continue.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        take = [{ true }]
+        prefix = [{ $($prefix:tt)* }]
+        first = [{ $first:tt }]
+        after_first = [{ $($rest:tt)* }]
+        $is:ident = [{ true }]
+    } => {
+        $crate::private_take! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            take = [{ true }]
+            prefix = [{ $($prefix)* $first }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Condition returned false while taking tokens that do not match.
+    // Continue.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        take = [{ false }]
+        prefix = [{ $($prefix:tt)* }]
+        first = [{ $first:tt }]
+        after_first = [{ $($rest:tt)* }]
+        $is:ident = [{ false }]
+    } => {
+        $crate::private_take! {
+            $caller
+            condition = [{ $($condition)::* }]
+            condition_args = [{ $($condition_args)* }]
+            take = [{ false }]
+            prefix = [{ $($prefix)* $first }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Condition returned the other value. Prefix is done, return to caller.
+    {
+        $caller:tt
+        condition = [{ $($condition:ident)::* }]
+        condition_args = [{ $($condition_args:tt)* }]
+        take = [{ $take:ident }]
+        prefix = [{ $($prefix:tt)* }]
+        first = [{ $first:tt }]
+        after_first = [{ $($rest:tt)* }]
+        $is:ident = [{ $other:ident }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            prefix = [{ $($prefix)* }]
+            rest = [{ $first $($rest)* }]
+        }
+    };
+}
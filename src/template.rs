@@ -0,0 +1,802 @@
+/// Substitute named values into a template of tokens.
+/// <sup>**[tt-call]**</sup>
+///
+/// Placeholders in the template are written `#name` and are replaced by the
+/// tokens of the value bound to `name`, similar to interpolation in the `quote`
+/// crate. Placeholders are found inside of parenthesized, square bracketed and
+/// curly braced groups at any depth. A `#` that is not followed by an
+/// identifier or a repetition is kept as is, so attributes like
+/// `#[derive(Debug)]` can be part of the template.
+///
+/// A repetition is written `#( ... )*`, or with a separator token between
+/// iterations as in `#( ... ),*`. Each placeholder inside of a repetition
+/// whose name is bound more than once refers to successive values of that name
+/// on successive iterations, and the repetition stops as soon as one of them
+/// runs out. A placeholder whose name is bound exactly once refers to the same
+/// value on every iteration, and a placeholder whose name is not bound at all
+/// is treated as a list of no values, so the repetition expands to nothing.
+///
+/// Outside of a repetition every placeholder must be bound exactly once.
+///
+/// The template is processed one token tree at a time and the body of a
+/// repetition once per iteration, so a crate that fills in large templates may
+/// need to raise its `#![recursion_limit]`.
+///
+/// # Input
+///
+///   - `template = [{` arbitrary tokens containing placeholders `}]`
+///   - **`$(`**<br>
+///     &emsp;&emsp;`$name = [{` value to substitute for `#$name` `}]`<br>
+///     **`)*`**
+///
+/// The values may be given in any order, which makes `tt_template!` suitable
+/// as the final destination of a `tt_call!` in order to splice the outputs of
+/// some other macro into a template. When used that way it is written without
+/// the `$caller`, as `~~> tt_template! { template = [{ ... }] }`, and expands
+/// directly to the filled in template.
+///
+/// # Output
+///
+///   - `tokens = [{` template after substitution `}]`
+///
+/// The bindings are turned into the rules of a helper macro, so the call must
+/// be in item or statement position; see [Position] in the crate
+/// documentation.
+///
+/// [Position]: index.html#position
+///
+/// # Example
+///
+/// ```
+/// #![recursion_limit = "256"]
+///
+/// use tt_call::{tt_call, tt_template};
+///
+/// macro_rules! make_struct {
+///     ($name:ident { $($field:ident: $ty:ty),* }) => {
+///         tt_call! {
+///             macro = [{ tt_template }]
+///             template = [{
+///                 #[derive(Debug, Default)]
+///                 struct #name {
+///                     #(#field: #ty,)*
+///                 }
+///             }]
+///             name = [{ $name }]
+///             $(
+///                 field = [{ $field }]
+///                 ty = [{ $ty }]
+///             )*
+///         }
+///     };
+/// }
+///
+/// make_struct!(Point { x: i32, y: i32 });
+///
+/// fn main() {
+///     let p = Point { x: 1, y: 2 };
+///     assert_eq!(format!("{:?}", p), "Point { x: 1, y: 2 }");
+/// }
+/// ```
+///
+/// Here the outputs of [`parse_type!`] are spliced into a template.
+///
+/// [`parse_type!`]: macro.parse_type.html
+///
+/// ```
+/// use tt_call::{parse_type, tt_call, tt_template};
+///
+/// macro_rules! boxed_fn {
+///     ($name:ident, $($input:tt)*) => {
+///         tt_call! {
+///             macro = [{ parse_type }]
+///             input = [{ $($input)* }]
+///             ~~> tt_template! {
+///                 template = [{
+///                     fn #name(value: #type) -> Box<#type> {
+///                         Box::new(value)
+///                     }
+///                 }]
+///                 name = [{ $name }]
+///             }
+///         }
+///     };
+/// }
+///
+/// boxed_fn!(boxed, Vec<u8>);
+///
+/// fn main() {
+///     assert_eq!(*boxed(vec![1, 2]), [1, 2]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! tt_template {
+    {
+        $caller:tt
+        template = [{ $($template:tt)* }]
+        $(
+            $name:ident = [{ $($value:tt)* }]
+        )*
+    } => {
+        $crate::private_template_lookup! {
+            ($)
+            $caller
+            names = [{ $($name)* }]
+            all = [{ $( $name = [{ $($value)* }] )* }]
+            template = [{ $($template)* }]
+        }
+    };
+
+    // Invoked as the final destination of a tt_call.
+    {
+        template = [{ $($template:tt)* }]
+        $(
+            $name:ident = [{ $($value:tt)* }]
+        )*
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::tt_template }]
+            template = [{ $($template)* }]
+            $(
+                $name = [{ $($value)* }]
+            )*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_template {
+    // Return from substituting the whole template. Return to caller.
+    {
+        $caller:tt
+        tokens = [{ $($tokens:tt)* }]
+        status = [{ $($status:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+        }
+    };
+
+    // A list of values ran out inside of a group during this iteration.
+    // Abandon the rest of the iteration.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ done }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ }]
+            status = [{ done }]
+        }
+    };
+
+    // Arrived at end of input. Return to caller, reporting whether any list of
+    // values was iterated.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+            status = [{ $($status)* }]
+        }
+    };
+
+    // Next tokens are a repetition without separator.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ # ( $($body:tt)* ) * $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_template_repetition }]
+            all = [{ $($all)* }]
+            body = [{ $($body)* }]
+            separator = [{ }]
+            index = [{ }]
+            output = [{ }]
+            ~~> $crate::private_template! {
+                $caller
+                all = [{ $($all)* }]
+                mode = [{ $($mode)* }]
+                status = [{ $($status)* }]
+                tokens = [{ $($tokens)* }]
+                after_repetition = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Next tokens are a repetition with separator.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ # ( $($body:tt)* ) $separator:tt * $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_template_repetition }]
+            all = [{ $($all)* }]
+            body = [{ $($body)* }]
+            separator = [{ $separator }]
+            index = [{ }]
+            output = [{ }]
+            ~~> $crate::private_template! {
+                $caller
+                all = [{ $($all)* }]
+                mode = [{ $($mode)* }]
+                status = [{ $($status)* }]
+                tokens = [{ $($tokens)* }]
+                after_repetition = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Return from expanding a repetition.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_repetition = [{ $($rest:tt)* }]
+        tokens = [{ $($repeated:tt)* }]
+    } => {
+        $crate::private_template! {
+            $caller
+            all = [{ $($all)* }]
+            mode = [{ $($mode)* }]
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* $($repeated)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Next tokens are a placeholder. Look up its values.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ # $name:ident $($rest:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ __tt_call_template_lookup }]
+            name = [{ $name }]
+            values = [{ }]
+            rest = [{ $($all)* }]
+            ~~> $crate::private_template! {
+                $caller
+                all = [{ $($all)* }]
+                mode = [{ $($mode)* }]
+                status = [{ $($status)* }]
+                tokens = [{ $($tokens)* }]
+                name = [{ $name }]
+                after_placeholder = [{ $($rest)* }]
+            }
+        }
+    };
+
+    // Return from looking up a placeholder that is bound exactly once.
+    // Substitute its value.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        name = [{ $name:ident }]
+        after_placeholder = [{ $($rest:tt)* }]
+        values = [{ [{ $($value:tt)* }] }]
+    } => {
+        $crate::private_template! {
+            $caller
+            all = [{ $($all)* }]
+            mode = [{ $($mode)* }]
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* $($value)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Unexpected: placeholder outside of a repetition is not bound exactly
+    // once.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ top }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        name = [{ $name:ident }]
+        after_placeholder = [{ $($rest:tt)* }]
+        values = [{ $($values:tt)* }]
+    } => {
+        $crate::error_unexpected! {
+            $name
+        }
+    };
+
+    // Return from looking up a placeholder inside of a repetition that is
+    // bound more than once or not at all. Select the value for the current
+    // iteration.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ repetition $($index:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        name = [{ $name:ident }]
+        after_placeholder = [{ $($rest:tt)* }]
+        values = [{ $($values:tt)* }]
+    } => {
+        $crate::private_template! {
+            $caller
+            all = [{ $($all)* }]
+            mode = [{ repetition $($index)* }]
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* }]
+            after_placeholder = [{ $($rest)* }]
+            skip = [{ $($index)* }]
+            values = [{ $($values)* }]
+        }
+    };
+
+    // Skip the values used by earlier iterations.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_placeholder = [{ $($rest:tt)* }]
+        skip = [{ 1 $($skip:tt)* }]
+        values = [{ $first:tt $($values:tt)* }]
+    } => {
+        $crate::private_template! {
+            $caller
+            all = [{ $($all)* }]
+            mode = [{ $($mode)* }]
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* }]
+            after_placeholder = [{ $($rest)* }]
+            skip = [{ $($skip)* }]
+            values = [{ $($values)* }]
+        }
+    };
+
+    // Substitute the value for the current iteration and record that a list
+    // of values is being iterated.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_placeholder = [{ $($rest:tt)* }]
+        skip = [{ }]
+        values = [{ [{ $($value:tt)* }] $($values:tt)* }]
+    } => {
+        $crate::private_template! {
+            $caller
+            all = [{ $($all)* }]
+            mode = [{ $($mode)* }]
+            status = [{ list }]
+            tokens = [{ $($tokens)* $($value)* }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // The list of values has run out. Abandon this iteration.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        after_placeholder = [{ $($rest:tt)* }]
+        skip = [{ $($skip:tt)* }]
+        values = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ }]
+            status = [{ done }]
+        }
+    };
+
+    // Next token tree is a group. Recurse to substitute contents.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        delimiter = [{ $delimiter:tt }]
+        group = [{ $($group:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_template }]
+            all = [{ $($all)* }]
+            mode = [{ $($mode)* }]
+            status = [{ $($status)* }]
+            tokens = [{ }]
+            rest = [{ $($group)* }]
+            ~~> $crate::private_template_tt! {
+                [$caller all = [{ $($all)* }] mode = [{ $($mode)* }]]
+                tokens = [{ $($tokens)* }]
+                rest = [{ $($rest)* }]
+                delimiter = [{ $delimiter }]
+            }
+        }
+    };
+
+    // Next token tree is not a placeholder or repetition. Find out whether it
+    // is a group.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        mode = [{ $($mode:tt)* }]
+        status = [{ $($status:tt)* }]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $first:tt $($rest:tt)* }]
+    } => {
+        $crate::private_template_tt! {
+            [$caller all = [{ $($all)* }] mode = [{ $($mode)* }] status = [{ $($status)* }]]
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            token = [{ $first }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_template_tt {
+    // The first three rules take apart a group into its delimiter and contents,
+    // and the next three put them back together after returning from
+    // substituting the contents. These are the only rules that depend on the
+    // kind of delimiter.
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        token = [{ ( $($group:tt)* ) }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            delimiter = [{ () }]
+            group = [{ $($group)* }]
+        }
+    };
+
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        token = [{ [ $($group:tt)* ] }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            delimiter = [{ [] }]
+            group = [{ $($group)* }]
+        }
+    };
+
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        token = [{ { $($group:tt)* } }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            tokens = [{ $($tokens)* }]
+            rest = [{ $($rest)* }]
+            delimiter = [{ {} }]
+            group = [{ $($group)* }]
+        }
+    };
+
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        delimiter = [{ () }]
+        tokens = [{ $($inside:tt)* }]
+        status = [{ $($status:tt)* }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* ( $($inside)* ) }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        delimiter = [{ [] }]
+        tokens = [{ $($inside:tt)* }]
+        status = [{ $($status:tt)* }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* [ $($inside)* ] }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $($rest:tt)* }]
+        delimiter = [{ {} }]
+        tokens = [{ $($inside:tt)* }]
+        status = [{ $($status:tt)* }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            status = [{ $($status)* }]
+            tokens = [{ $($tokens)* { $($inside)* } }]
+            rest = [{ $($rest)* }]
+        }
+    };
+
+    // Next token is not a group and the one after it may start a placeholder
+    // or repetition. Keep it and go back to looking for those.
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ # $($rest:tt)* }]
+        token = [{ $first:tt }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            tokens = [{ $($tokens)* $first }]
+            rest = [{ # $($rest)* }]
+        }
+    };
+
+    // Next token is not a group and the one after it cannot start a
+    // placeholder. Keep it and look at the one after it without another step
+    // in between.
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ $next:tt $($rest:tt)* }]
+        token = [{ $first:tt }]
+    } => {
+        $crate::private_template_tt! {
+            [$($state)*]
+            tokens = [{ $($tokens)* $first }]
+            rest = [{ $($rest)* }]
+            token = [{ $next }]
+        }
+    };
+
+    // Last token is not a group. Keep it and go back to the end of input rule.
+    {
+        [$($state:tt)*]
+        tokens = [{ $($tokens:tt)* }]
+        rest = [{ }]
+        token = [{ $first:tt }]
+    } => {
+        $crate::private_template! {
+            $($state)*
+            tokens = [{ $($tokens)* $first }]
+            rest = [{ }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_template_repetition {
+    // Substitute the body for the iteration with the given index.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        body = [{ $($body:tt)* }]
+        separator = [{ $($separator:tt)* }]
+        index = [{ $($index:tt)* }]
+        output = [{ $($output:tt)* }]
+    } => {
+        $crate::tt_call! {
+            macro = [{ $crate::private_template }]
+            all = [{ $($all)* }]
+            mode = [{ repetition $($index)* }]
+            status = [{ }]
+            tokens = [{ }]
+            rest = [{ $($body)* }]
+            ~~> $crate::private_template_repetition! {
+                $caller
+                all = [{ $($all)* }]
+                body = [{ $($body)* }]
+                separator = [{ $($separator)* }]
+                index = [{ $($index)* }]
+                output = [{ $($output)* }]
+                after_iteration = [{ }]
+            }
+        }
+    };
+
+    // A list of values ran out during this iteration. Return to caller.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        body = [{ $($body:tt)* }]
+        separator = [{ $($separator:tt)* }]
+        index = [{ $($index:tt)* }]
+        output = [{ $($output:tt)* }]
+        after_iteration = [{ }]
+        tokens = [{ $($tokens:tt)* }]
+        status = [{ done }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($output)* }]
+        }
+    };
+
+    // The body does not iterate any list of values, so it is expanded once.
+    // Return to caller.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        body = [{ $($body:tt)* }]
+        separator = [{ $($separator:tt)* }]
+        index = [{ $($index:tt)* }]
+        output = [{ $($output:tt)* }]
+        after_iteration = [{ }]
+        tokens = [{ $($tokens:tt)* }]
+        status = [{ }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            tokens = [{ $($tokens)* }]
+        }
+    };
+
+    // Finished the first iteration. Continue with the next one.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        body = [{ $($body:tt)* }]
+        separator = [{ $($separator:tt)* }]
+        index = [{ }]
+        output = [{ }]
+        after_iteration = [{ }]
+        tokens = [{ $($tokens:tt)* }]
+        status = [{ list }]
+    } => {
+        $crate::private_template_repetition! {
+            $caller
+            all = [{ $($all)* }]
+            body = [{ $($body)* }]
+            separator = [{ $($separator)* }]
+            index = [{ 1 }]
+            output = [{ $($tokens)* }]
+        }
+    };
+
+    // Finished a later iteration. Insert the separator and continue with the
+    // next one.
+    {
+        $caller:tt
+        all = [{ $($all:tt)* }]
+        body = [{ $($body:tt)* }]
+        separator = [{ $($separator:tt)* }]
+        index = [{ $($index:tt)+ }]
+        output = [{ $($output:tt)* }]
+        after_iteration = [{ }]
+        tokens = [{ $($tokens:tt)* }]
+        status = [{ list }]
+    } => {
+        $crate::private_template_repetition! {
+            $caller
+            all = [{ $($all)* }]
+            body = [{ $($body)* }]
+            separator = [{ $($separator)* }]
+            index = [{ $($index)* 1 }]
+            output = [{ $($output)* $($separator)* $($tokens)* }]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_template_lookup {
+    // Define a tt-muncher with one rule per binding that collects the values
+    // bound to a given name, and substitute the template using it. See
+    // `private_eq!` for the use of `$d`.
+    {
+        ($d:tt)
+        $caller:tt
+        names = [{ $($name:ident)* }]
+        all = [{ $($all:tt)* }]
+        template = [{ $($template:tt)* }]
+    } => {
+        macro_rules! __tt_call_template_lookup {
+            // Arrived at end of bindings. Return to caller.
+            {
+                $d __tt_caller:tt
+                name = [{ $d __tt_name:ident }]
+                values = [{ $d($d __tt_values:tt)* }]
+                rest = [{ }]
+            } => {
+                $crate::tt_return! {
+                    $d __tt_caller
+                    values = [{ $d($d __tt_values)* }]
+                }
+            };
+
+            $(
+                // Next binding has the name being looked up.
+                {
+                    $d __tt_caller:tt
+                    name = [{ $name }]
+                    values = [{ $d($d __tt_values:tt)* }]
+                    rest = [{ $name = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+                } => {
+                    __tt_call_template_lookup! {
+                        $d __tt_caller
+                        name = [{ $name }]
+                        values = [{ $d($d __tt_values)* [{ $d($d __tt_value)* }] }]
+                        rest = [{ $d($d __tt_rest)* }]
+                    }
+                };
+            )*
+
+            // Next binding has some other name.
+            {
+                $d __tt_caller:tt
+                name = [{ $d __tt_name:ident }]
+                values = [{ $d($d __tt_values:tt)* }]
+                rest = [{ $d __tt_other:ident = [{ $d($d __tt_value:tt)* }] $d($d __tt_rest:tt)* }]
+            } => {
+                __tt_call_template_lookup! {
+                    $d __tt_caller
+                    name = [{ $d __tt_name }]
+                    values = [{ $d($d __tt_values)* }]
+                    rest = [{ $d($d __tt_rest)* }]
+                }
+            };
+        }
+
+        $crate::tt_call! {
+            macro = [{ $crate::private_template }]
+            all = [{ $($all)* }]
+            mode = [{ top }]
+            status = [{ }]
+            tokens = [{ }]
+            rest = [{ $($template)* }]
+            ~~> $crate::private_template! {
+                $caller
+            }
+        }
+    };
+}
@@ -0,0 +1,1161 @@
+// Conversion of small integer literals to unary, for inputs like a depth limit
+// that are more pleasant to write as a number but need to be counted down one
+// step at a time by a tt-muncher. The unary form holds one `1` token per unit,
+// so that `0 $(+ $one)*` turns it back into an integer expression.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_unary {
+    { $caller:tt input = [{ 0 }] } => { $crate::tt_return! { $caller unary = [{ }] } };
+    { $caller:tt input = [{ 1 }] } => { $crate::tt_return! { $caller unary = [{ 1 }] } };
+    { $caller:tt input = [{ 2 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 }] } };
+    { $caller:tt input = [{ 3 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 }] } };
+    { $caller:tt input = [{ 4 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 }] } };
+    { $caller:tt input = [{ 5 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 6 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 7 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 8 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 9 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 10 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 11 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 12 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 13 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 14 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 15 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 16 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 17 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 18 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 19 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 20 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 21 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 22 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 23 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 24 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 25 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 26 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 27 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 28 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 29 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 30 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 31 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 32 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 33 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 34 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 35 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 36 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 37 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 38 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 39 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 40 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 41 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 42 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 43 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 44 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 45 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 46 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 47 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 48 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 49 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 50 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 51 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 52 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 53 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 54 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 55 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 56 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 57 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 58 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 59 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 60 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 61 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 62 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 63 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+    { $caller:tt input = [{ 64 }] } => { $crate::tt_return! { $caller unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } };
+
+    // Unexpected: not an integer literal in the supported range.
+    {
+        $caller:tt
+        input = [{ $($unexpected:tt)+ }]
+    } => {
+        $crate::error_unexpected! {
+            $($unexpected)*
+        }
+    };
+
+    // Unexpected: input is empty.
+    {
+        $caller:tt
+        input = [{ }]
+    } => {
+        $crate::error_eof! {}
+    };
+}
+
+// Conversion of integer literals up to 999 to their three decimal digits, for
+// inputs like a recursion budget that are too large to carry in unary. Each
+// digit can then be converted with the table above.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_decimal {
+    { $caller:tt input = [{ 0 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 0 }] } };
+    { $caller:tt input = [{ 1 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 1 }] } };
+    { $caller:tt input = [{ 2 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 2 }] } };
+    { $caller:tt input = [{ 3 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 3 }] } };
+    { $caller:tt input = [{ 4 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 4 }] } };
+    { $caller:tt input = [{ 5 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 5 }] } };
+    { $caller:tt input = [{ 6 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 6 }] } };
+    { $caller:tt input = [{ 7 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 7 }] } };
+    { $caller:tt input = [{ 8 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 8 }] } };
+    { $caller:tt input = [{ 9 }] } => { $crate::tt_return! { $caller digits = [{ 0 0 9 }] } };
+    { $caller:tt input = [{ 10 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 0 }] } };
+    { $caller:tt input = [{ 11 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 1 }] } };
+    { $caller:tt input = [{ 12 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 2 }] } };
+    { $caller:tt input = [{ 13 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 3 }] } };
+    { $caller:tt input = [{ 14 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 4 }] } };
+    { $caller:tt input = [{ 15 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 5 }] } };
+    { $caller:tt input = [{ 16 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 6 }] } };
+    { $caller:tt input = [{ 17 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 7 }] } };
+    { $caller:tt input = [{ 18 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 8 }] } };
+    { $caller:tt input = [{ 19 }] } => { $crate::tt_return! { $caller digits = [{ 0 1 9 }] } };
+    { $caller:tt input = [{ 20 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 0 }] } };
+    { $caller:tt input = [{ 21 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 1 }] } };
+    { $caller:tt input = [{ 22 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 2 }] } };
+    { $caller:tt input = [{ 23 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 3 }] } };
+    { $caller:tt input = [{ 24 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 4 }] } };
+    { $caller:tt input = [{ 25 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 5 }] } };
+    { $caller:tt input = [{ 26 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 6 }] } };
+    { $caller:tt input = [{ 27 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 7 }] } };
+    { $caller:tt input = [{ 28 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 8 }] } };
+    { $caller:tt input = [{ 29 }] } => { $crate::tt_return! { $caller digits = [{ 0 2 9 }] } };
+    { $caller:tt input = [{ 30 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 0 }] } };
+    { $caller:tt input = [{ 31 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 1 }] } };
+    { $caller:tt input = [{ 32 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 2 }] } };
+    { $caller:tt input = [{ 33 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 3 }] } };
+    { $caller:tt input = [{ 34 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 4 }] } };
+    { $caller:tt input = [{ 35 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 5 }] } };
+    { $caller:tt input = [{ 36 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 6 }] } };
+    { $caller:tt input = [{ 37 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 7 }] } };
+    { $caller:tt input = [{ 38 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 8 }] } };
+    { $caller:tt input = [{ 39 }] } => { $crate::tt_return! { $caller digits = [{ 0 3 9 }] } };
+    { $caller:tt input = [{ 40 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 0 }] } };
+    { $caller:tt input = [{ 41 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 1 }] } };
+    { $caller:tt input = [{ 42 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 2 }] } };
+    { $caller:tt input = [{ 43 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 3 }] } };
+    { $caller:tt input = [{ 44 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 4 }] } };
+    { $caller:tt input = [{ 45 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 5 }] } };
+    { $caller:tt input = [{ 46 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 6 }] } };
+    { $caller:tt input = [{ 47 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 7 }] } };
+    { $caller:tt input = [{ 48 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 8 }] } };
+    { $caller:tt input = [{ 49 }] } => { $crate::tt_return! { $caller digits = [{ 0 4 9 }] } };
+    { $caller:tt input = [{ 50 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 0 }] } };
+    { $caller:tt input = [{ 51 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 1 }] } };
+    { $caller:tt input = [{ 52 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 2 }] } };
+    { $caller:tt input = [{ 53 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 3 }] } };
+    { $caller:tt input = [{ 54 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 4 }] } };
+    { $caller:tt input = [{ 55 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 5 }] } };
+    { $caller:tt input = [{ 56 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 6 }] } };
+    { $caller:tt input = [{ 57 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 7 }] } };
+    { $caller:tt input = [{ 58 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 8 }] } };
+    { $caller:tt input = [{ 59 }] } => { $crate::tt_return! { $caller digits = [{ 0 5 9 }] } };
+    { $caller:tt input = [{ 60 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 0 }] } };
+    { $caller:tt input = [{ 61 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 1 }] } };
+    { $caller:tt input = [{ 62 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 2 }] } };
+    { $caller:tt input = [{ 63 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 3 }] } };
+    { $caller:tt input = [{ 64 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 4 }] } };
+    { $caller:tt input = [{ 65 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 5 }] } };
+    { $caller:tt input = [{ 66 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 6 }] } };
+    { $caller:tt input = [{ 67 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 7 }] } };
+    { $caller:tt input = [{ 68 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 8 }] } };
+    { $caller:tt input = [{ 69 }] } => { $crate::tt_return! { $caller digits = [{ 0 6 9 }] } };
+    { $caller:tt input = [{ 70 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 0 }] } };
+    { $caller:tt input = [{ 71 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 1 }] } };
+    { $caller:tt input = [{ 72 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 2 }] } };
+    { $caller:tt input = [{ 73 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 3 }] } };
+    { $caller:tt input = [{ 74 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 4 }] } };
+    { $caller:tt input = [{ 75 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 5 }] } };
+    { $caller:tt input = [{ 76 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 6 }] } };
+    { $caller:tt input = [{ 77 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 7 }] } };
+    { $caller:tt input = [{ 78 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 8 }] } };
+    { $caller:tt input = [{ 79 }] } => { $crate::tt_return! { $caller digits = [{ 0 7 9 }] } };
+    { $caller:tt input = [{ 80 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 0 }] } };
+    { $caller:tt input = [{ 81 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 1 }] } };
+    { $caller:tt input = [{ 82 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 2 }] } };
+    { $caller:tt input = [{ 83 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 3 }] } };
+    { $caller:tt input = [{ 84 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 4 }] } };
+    { $caller:tt input = [{ 85 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 5 }] } };
+    { $caller:tt input = [{ 86 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 6 }] } };
+    { $caller:tt input = [{ 87 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 7 }] } };
+    { $caller:tt input = [{ 88 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 8 }] } };
+    { $caller:tt input = [{ 89 }] } => { $crate::tt_return! { $caller digits = [{ 0 8 9 }] } };
+    { $caller:tt input = [{ 90 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 0 }] } };
+    { $caller:tt input = [{ 91 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 1 }] } };
+    { $caller:tt input = [{ 92 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 2 }] } };
+    { $caller:tt input = [{ 93 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 3 }] } };
+    { $caller:tt input = [{ 94 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 4 }] } };
+    { $caller:tt input = [{ 95 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 5 }] } };
+    { $caller:tt input = [{ 96 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 6 }] } };
+    { $caller:tt input = [{ 97 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 7 }] } };
+    { $caller:tt input = [{ 98 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 8 }] } };
+    { $caller:tt input = [{ 99 }] } => { $crate::tt_return! { $caller digits = [{ 0 9 9 }] } };
+    { $caller:tt input = [{ 100 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 0 }] } };
+    { $caller:tt input = [{ 101 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 1 }] } };
+    { $caller:tt input = [{ 102 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 2 }] } };
+    { $caller:tt input = [{ 103 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 3 }] } };
+    { $caller:tt input = [{ 104 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 4 }] } };
+    { $caller:tt input = [{ 105 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 5 }] } };
+    { $caller:tt input = [{ 106 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 6 }] } };
+    { $caller:tt input = [{ 107 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 7 }] } };
+    { $caller:tt input = [{ 108 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 8 }] } };
+    { $caller:tt input = [{ 109 }] } => { $crate::tt_return! { $caller digits = [{ 1 0 9 }] } };
+    { $caller:tt input = [{ 110 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 0 }] } };
+    { $caller:tt input = [{ 111 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 1 }] } };
+    { $caller:tt input = [{ 112 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 2 }] } };
+    { $caller:tt input = [{ 113 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 3 }] } };
+    { $caller:tt input = [{ 114 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 4 }] } };
+    { $caller:tt input = [{ 115 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 5 }] } };
+    { $caller:tt input = [{ 116 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 6 }] } };
+    { $caller:tt input = [{ 117 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 7 }] } };
+    { $caller:tt input = [{ 118 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 8 }] } };
+    { $caller:tt input = [{ 119 }] } => { $crate::tt_return! { $caller digits = [{ 1 1 9 }] } };
+    { $caller:tt input = [{ 120 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 0 }] } };
+    { $caller:tt input = [{ 121 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 1 }] } };
+    { $caller:tt input = [{ 122 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 2 }] } };
+    { $caller:tt input = [{ 123 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 3 }] } };
+    { $caller:tt input = [{ 124 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 4 }] } };
+    { $caller:tt input = [{ 125 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 5 }] } };
+    { $caller:tt input = [{ 126 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 6 }] } };
+    { $caller:tt input = [{ 127 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 7 }] } };
+    { $caller:tt input = [{ 128 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 8 }] } };
+    { $caller:tt input = [{ 129 }] } => { $crate::tt_return! { $caller digits = [{ 1 2 9 }] } };
+    { $caller:tt input = [{ 130 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 0 }] } };
+    { $caller:tt input = [{ 131 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 1 }] } };
+    { $caller:tt input = [{ 132 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 2 }] } };
+    { $caller:tt input = [{ 133 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 3 }] } };
+    { $caller:tt input = [{ 134 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 4 }] } };
+    { $caller:tt input = [{ 135 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 5 }] } };
+    { $caller:tt input = [{ 136 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 6 }] } };
+    { $caller:tt input = [{ 137 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 7 }] } };
+    { $caller:tt input = [{ 138 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 8 }] } };
+    { $caller:tt input = [{ 139 }] } => { $crate::tt_return! { $caller digits = [{ 1 3 9 }] } };
+    { $caller:tt input = [{ 140 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 0 }] } };
+    { $caller:tt input = [{ 141 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 1 }] } };
+    { $caller:tt input = [{ 142 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 2 }] } };
+    { $caller:tt input = [{ 143 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 3 }] } };
+    { $caller:tt input = [{ 144 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 4 }] } };
+    { $caller:tt input = [{ 145 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 5 }] } };
+    { $caller:tt input = [{ 146 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 6 }] } };
+    { $caller:tt input = [{ 147 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 7 }] } };
+    { $caller:tt input = [{ 148 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 8 }] } };
+    { $caller:tt input = [{ 149 }] } => { $crate::tt_return! { $caller digits = [{ 1 4 9 }] } };
+    { $caller:tt input = [{ 150 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 0 }] } };
+    { $caller:tt input = [{ 151 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 1 }] } };
+    { $caller:tt input = [{ 152 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 2 }] } };
+    { $caller:tt input = [{ 153 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 3 }] } };
+    { $caller:tt input = [{ 154 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 4 }] } };
+    { $caller:tt input = [{ 155 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 5 }] } };
+    { $caller:tt input = [{ 156 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 6 }] } };
+    { $caller:tt input = [{ 157 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 7 }] } };
+    { $caller:tt input = [{ 158 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 8 }] } };
+    { $caller:tt input = [{ 159 }] } => { $crate::tt_return! { $caller digits = [{ 1 5 9 }] } };
+    { $caller:tt input = [{ 160 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 0 }] } };
+    { $caller:tt input = [{ 161 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 1 }] } };
+    { $caller:tt input = [{ 162 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 2 }] } };
+    { $caller:tt input = [{ 163 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 3 }] } };
+    { $caller:tt input = [{ 164 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 4 }] } };
+    { $caller:tt input = [{ 165 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 5 }] } };
+    { $caller:tt input = [{ 166 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 6 }] } };
+    { $caller:tt input = [{ 167 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 7 }] } };
+    { $caller:tt input = [{ 168 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 8 }] } };
+    { $caller:tt input = [{ 169 }] } => { $crate::tt_return! { $caller digits = [{ 1 6 9 }] } };
+    { $caller:tt input = [{ 170 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 0 }] } };
+    { $caller:tt input = [{ 171 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 1 }] } };
+    { $caller:tt input = [{ 172 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 2 }] } };
+    { $caller:tt input = [{ 173 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 3 }] } };
+    { $caller:tt input = [{ 174 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 4 }] } };
+    { $caller:tt input = [{ 175 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 5 }] } };
+    { $caller:tt input = [{ 176 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 6 }] } };
+    { $caller:tt input = [{ 177 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 7 }] } };
+    { $caller:tt input = [{ 178 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 8 }] } };
+    { $caller:tt input = [{ 179 }] } => { $crate::tt_return! { $caller digits = [{ 1 7 9 }] } };
+    { $caller:tt input = [{ 180 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 0 }] } };
+    { $caller:tt input = [{ 181 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 1 }] } };
+    { $caller:tt input = [{ 182 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 2 }] } };
+    { $caller:tt input = [{ 183 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 3 }] } };
+    { $caller:tt input = [{ 184 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 4 }] } };
+    { $caller:tt input = [{ 185 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 5 }] } };
+    { $caller:tt input = [{ 186 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 6 }] } };
+    { $caller:tt input = [{ 187 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 7 }] } };
+    { $caller:tt input = [{ 188 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 8 }] } };
+    { $caller:tt input = [{ 189 }] } => { $crate::tt_return! { $caller digits = [{ 1 8 9 }] } };
+    { $caller:tt input = [{ 190 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 0 }] } };
+    { $caller:tt input = [{ 191 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 1 }] } };
+    { $caller:tt input = [{ 192 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 2 }] } };
+    { $caller:tt input = [{ 193 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 3 }] } };
+    { $caller:tt input = [{ 194 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 4 }] } };
+    { $caller:tt input = [{ 195 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 5 }] } };
+    { $caller:tt input = [{ 196 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 6 }] } };
+    { $caller:tt input = [{ 197 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 7 }] } };
+    { $caller:tt input = [{ 198 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 8 }] } };
+    { $caller:tt input = [{ 199 }] } => { $crate::tt_return! { $caller digits = [{ 1 9 9 }] } };
+    { $caller:tt input = [{ 200 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 0 }] } };
+    { $caller:tt input = [{ 201 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 1 }] } };
+    { $caller:tt input = [{ 202 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 2 }] } };
+    { $caller:tt input = [{ 203 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 3 }] } };
+    { $caller:tt input = [{ 204 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 4 }] } };
+    { $caller:tt input = [{ 205 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 5 }] } };
+    { $caller:tt input = [{ 206 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 6 }] } };
+    { $caller:tt input = [{ 207 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 7 }] } };
+    { $caller:tt input = [{ 208 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 8 }] } };
+    { $caller:tt input = [{ 209 }] } => { $crate::tt_return! { $caller digits = [{ 2 0 9 }] } };
+    { $caller:tt input = [{ 210 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 0 }] } };
+    { $caller:tt input = [{ 211 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 1 }] } };
+    { $caller:tt input = [{ 212 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 2 }] } };
+    { $caller:tt input = [{ 213 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 3 }] } };
+    { $caller:tt input = [{ 214 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 4 }] } };
+    { $caller:tt input = [{ 215 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 5 }] } };
+    { $caller:tt input = [{ 216 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 6 }] } };
+    { $caller:tt input = [{ 217 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 7 }] } };
+    { $caller:tt input = [{ 218 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 8 }] } };
+    { $caller:tt input = [{ 219 }] } => { $crate::tt_return! { $caller digits = [{ 2 1 9 }] } };
+    { $caller:tt input = [{ 220 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 0 }] } };
+    { $caller:tt input = [{ 221 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 1 }] } };
+    { $caller:tt input = [{ 222 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 2 }] } };
+    { $caller:tt input = [{ 223 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 3 }] } };
+    { $caller:tt input = [{ 224 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 4 }] } };
+    { $caller:tt input = [{ 225 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 5 }] } };
+    { $caller:tt input = [{ 226 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 6 }] } };
+    { $caller:tt input = [{ 227 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 7 }] } };
+    { $caller:tt input = [{ 228 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 8 }] } };
+    { $caller:tt input = [{ 229 }] } => { $crate::tt_return! { $caller digits = [{ 2 2 9 }] } };
+    { $caller:tt input = [{ 230 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 0 }] } };
+    { $caller:tt input = [{ 231 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 1 }] } };
+    { $caller:tt input = [{ 232 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 2 }] } };
+    { $caller:tt input = [{ 233 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 3 }] } };
+    { $caller:tt input = [{ 234 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 4 }] } };
+    { $caller:tt input = [{ 235 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 5 }] } };
+    { $caller:tt input = [{ 236 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 6 }] } };
+    { $caller:tt input = [{ 237 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 7 }] } };
+    { $caller:tt input = [{ 238 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 8 }] } };
+    { $caller:tt input = [{ 239 }] } => { $crate::tt_return! { $caller digits = [{ 2 3 9 }] } };
+    { $caller:tt input = [{ 240 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 0 }] } };
+    { $caller:tt input = [{ 241 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 1 }] } };
+    { $caller:tt input = [{ 242 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 2 }] } };
+    { $caller:tt input = [{ 243 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 3 }] } };
+    { $caller:tt input = [{ 244 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 4 }] } };
+    { $caller:tt input = [{ 245 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 5 }] } };
+    { $caller:tt input = [{ 246 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 6 }] } };
+    { $caller:tt input = [{ 247 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 7 }] } };
+    { $caller:tt input = [{ 248 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 8 }] } };
+    { $caller:tt input = [{ 249 }] } => { $crate::tt_return! { $caller digits = [{ 2 4 9 }] } };
+    { $caller:tt input = [{ 250 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 0 }] } };
+    { $caller:tt input = [{ 251 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 1 }] } };
+    { $caller:tt input = [{ 252 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 2 }] } };
+    { $caller:tt input = [{ 253 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 3 }] } };
+    { $caller:tt input = [{ 254 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 4 }] } };
+    { $caller:tt input = [{ 255 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 5 }] } };
+    { $caller:tt input = [{ 256 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 6 }] } };
+    { $caller:tt input = [{ 257 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 7 }] } };
+    { $caller:tt input = [{ 258 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 8 }] } };
+    { $caller:tt input = [{ 259 }] } => { $crate::tt_return! { $caller digits = [{ 2 5 9 }] } };
+    { $caller:tt input = [{ 260 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 0 }] } };
+    { $caller:tt input = [{ 261 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 1 }] } };
+    { $caller:tt input = [{ 262 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 2 }] } };
+    { $caller:tt input = [{ 263 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 3 }] } };
+    { $caller:tt input = [{ 264 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 4 }] } };
+    { $caller:tt input = [{ 265 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 5 }] } };
+    { $caller:tt input = [{ 266 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 6 }] } };
+    { $caller:tt input = [{ 267 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 7 }] } };
+    { $caller:tt input = [{ 268 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 8 }] } };
+    { $caller:tt input = [{ 269 }] } => { $crate::tt_return! { $caller digits = [{ 2 6 9 }] } };
+    { $caller:tt input = [{ 270 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 0 }] } };
+    { $caller:tt input = [{ 271 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 1 }] } };
+    { $caller:tt input = [{ 272 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 2 }] } };
+    { $caller:tt input = [{ 273 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 3 }] } };
+    { $caller:tt input = [{ 274 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 4 }] } };
+    { $caller:tt input = [{ 275 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 5 }] } };
+    { $caller:tt input = [{ 276 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 6 }] } };
+    { $caller:tt input = [{ 277 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 7 }] } };
+    { $caller:tt input = [{ 278 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 8 }] } };
+    { $caller:tt input = [{ 279 }] } => { $crate::tt_return! { $caller digits = [{ 2 7 9 }] } };
+    { $caller:tt input = [{ 280 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 0 }] } };
+    { $caller:tt input = [{ 281 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 1 }] } };
+    { $caller:tt input = [{ 282 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 2 }] } };
+    { $caller:tt input = [{ 283 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 3 }] } };
+    { $caller:tt input = [{ 284 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 4 }] } };
+    { $caller:tt input = [{ 285 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 5 }] } };
+    { $caller:tt input = [{ 286 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 6 }] } };
+    { $caller:tt input = [{ 287 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 7 }] } };
+    { $caller:tt input = [{ 288 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 8 }] } };
+    { $caller:tt input = [{ 289 }] } => { $crate::tt_return! { $caller digits = [{ 2 8 9 }] } };
+    { $caller:tt input = [{ 290 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 0 }] } };
+    { $caller:tt input = [{ 291 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 1 }] } };
+    { $caller:tt input = [{ 292 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 2 }] } };
+    { $caller:tt input = [{ 293 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 3 }] } };
+    { $caller:tt input = [{ 294 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 4 }] } };
+    { $caller:tt input = [{ 295 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 5 }] } };
+    { $caller:tt input = [{ 296 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 6 }] } };
+    { $caller:tt input = [{ 297 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 7 }] } };
+    { $caller:tt input = [{ 298 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 8 }] } };
+    { $caller:tt input = [{ 299 }] } => { $crate::tt_return! { $caller digits = [{ 2 9 9 }] } };
+    { $caller:tt input = [{ 300 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 0 }] } };
+    { $caller:tt input = [{ 301 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 1 }] } };
+    { $caller:tt input = [{ 302 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 2 }] } };
+    { $caller:tt input = [{ 303 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 3 }] } };
+    { $caller:tt input = [{ 304 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 4 }] } };
+    { $caller:tt input = [{ 305 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 5 }] } };
+    { $caller:tt input = [{ 306 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 6 }] } };
+    { $caller:tt input = [{ 307 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 7 }] } };
+    { $caller:tt input = [{ 308 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 8 }] } };
+    { $caller:tt input = [{ 309 }] } => { $crate::tt_return! { $caller digits = [{ 3 0 9 }] } };
+    { $caller:tt input = [{ 310 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 0 }] } };
+    { $caller:tt input = [{ 311 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 1 }] } };
+    { $caller:tt input = [{ 312 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 2 }] } };
+    { $caller:tt input = [{ 313 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 3 }] } };
+    { $caller:tt input = [{ 314 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 4 }] } };
+    { $caller:tt input = [{ 315 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 5 }] } };
+    { $caller:tt input = [{ 316 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 6 }] } };
+    { $caller:tt input = [{ 317 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 7 }] } };
+    { $caller:tt input = [{ 318 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 8 }] } };
+    { $caller:tt input = [{ 319 }] } => { $crate::tt_return! { $caller digits = [{ 3 1 9 }] } };
+    { $caller:tt input = [{ 320 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 0 }] } };
+    { $caller:tt input = [{ 321 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 1 }] } };
+    { $caller:tt input = [{ 322 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 2 }] } };
+    { $caller:tt input = [{ 323 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 3 }] } };
+    { $caller:tt input = [{ 324 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 4 }] } };
+    { $caller:tt input = [{ 325 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 5 }] } };
+    { $caller:tt input = [{ 326 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 6 }] } };
+    { $caller:tt input = [{ 327 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 7 }] } };
+    { $caller:tt input = [{ 328 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 8 }] } };
+    { $caller:tt input = [{ 329 }] } => { $crate::tt_return! { $caller digits = [{ 3 2 9 }] } };
+    { $caller:tt input = [{ 330 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 0 }] } };
+    { $caller:tt input = [{ 331 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 1 }] } };
+    { $caller:tt input = [{ 332 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 2 }] } };
+    { $caller:tt input = [{ 333 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 3 }] } };
+    { $caller:tt input = [{ 334 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 4 }] } };
+    { $caller:tt input = [{ 335 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 5 }] } };
+    { $caller:tt input = [{ 336 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 6 }] } };
+    { $caller:tt input = [{ 337 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 7 }] } };
+    { $caller:tt input = [{ 338 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 8 }] } };
+    { $caller:tt input = [{ 339 }] } => { $crate::tt_return! { $caller digits = [{ 3 3 9 }] } };
+    { $caller:tt input = [{ 340 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 0 }] } };
+    { $caller:tt input = [{ 341 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 1 }] } };
+    { $caller:tt input = [{ 342 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 2 }] } };
+    { $caller:tt input = [{ 343 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 3 }] } };
+    { $caller:tt input = [{ 344 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 4 }] } };
+    { $caller:tt input = [{ 345 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 5 }] } };
+    { $caller:tt input = [{ 346 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 6 }] } };
+    { $caller:tt input = [{ 347 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 7 }] } };
+    { $caller:tt input = [{ 348 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 8 }] } };
+    { $caller:tt input = [{ 349 }] } => { $crate::tt_return! { $caller digits = [{ 3 4 9 }] } };
+    { $caller:tt input = [{ 350 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 0 }] } };
+    { $caller:tt input = [{ 351 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 1 }] } };
+    { $caller:tt input = [{ 352 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 2 }] } };
+    { $caller:tt input = [{ 353 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 3 }] } };
+    { $caller:tt input = [{ 354 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 4 }] } };
+    { $caller:tt input = [{ 355 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 5 }] } };
+    { $caller:tt input = [{ 356 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 6 }] } };
+    { $caller:tt input = [{ 357 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 7 }] } };
+    { $caller:tt input = [{ 358 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 8 }] } };
+    { $caller:tt input = [{ 359 }] } => { $crate::tt_return! { $caller digits = [{ 3 5 9 }] } };
+    { $caller:tt input = [{ 360 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 0 }] } };
+    { $caller:tt input = [{ 361 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 1 }] } };
+    { $caller:tt input = [{ 362 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 2 }] } };
+    { $caller:tt input = [{ 363 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 3 }] } };
+    { $caller:tt input = [{ 364 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 4 }] } };
+    { $caller:tt input = [{ 365 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 5 }] } };
+    { $caller:tt input = [{ 366 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 6 }] } };
+    { $caller:tt input = [{ 367 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 7 }] } };
+    { $caller:tt input = [{ 368 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 8 }] } };
+    { $caller:tt input = [{ 369 }] } => { $crate::tt_return! { $caller digits = [{ 3 6 9 }] } };
+    { $caller:tt input = [{ 370 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 0 }] } };
+    { $caller:tt input = [{ 371 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 1 }] } };
+    { $caller:tt input = [{ 372 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 2 }] } };
+    { $caller:tt input = [{ 373 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 3 }] } };
+    { $caller:tt input = [{ 374 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 4 }] } };
+    { $caller:tt input = [{ 375 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 5 }] } };
+    { $caller:tt input = [{ 376 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 6 }] } };
+    { $caller:tt input = [{ 377 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 7 }] } };
+    { $caller:tt input = [{ 378 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 8 }] } };
+    { $caller:tt input = [{ 379 }] } => { $crate::tt_return! { $caller digits = [{ 3 7 9 }] } };
+    { $caller:tt input = [{ 380 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 0 }] } };
+    { $caller:tt input = [{ 381 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 1 }] } };
+    { $caller:tt input = [{ 382 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 2 }] } };
+    { $caller:tt input = [{ 383 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 3 }] } };
+    { $caller:tt input = [{ 384 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 4 }] } };
+    { $caller:tt input = [{ 385 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 5 }] } };
+    { $caller:tt input = [{ 386 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 6 }] } };
+    { $caller:tt input = [{ 387 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 7 }] } };
+    { $caller:tt input = [{ 388 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 8 }] } };
+    { $caller:tt input = [{ 389 }] } => { $crate::tt_return! { $caller digits = [{ 3 8 9 }] } };
+    { $caller:tt input = [{ 390 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 0 }] } };
+    { $caller:tt input = [{ 391 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 1 }] } };
+    { $caller:tt input = [{ 392 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 2 }] } };
+    { $caller:tt input = [{ 393 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 3 }] } };
+    { $caller:tt input = [{ 394 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 4 }] } };
+    { $caller:tt input = [{ 395 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 5 }] } };
+    { $caller:tt input = [{ 396 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 6 }] } };
+    { $caller:tt input = [{ 397 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 7 }] } };
+    { $caller:tt input = [{ 398 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 8 }] } };
+    { $caller:tt input = [{ 399 }] } => { $crate::tt_return! { $caller digits = [{ 3 9 9 }] } };
+    { $caller:tt input = [{ 400 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 0 }] } };
+    { $caller:tt input = [{ 401 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 1 }] } };
+    { $caller:tt input = [{ 402 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 2 }] } };
+    { $caller:tt input = [{ 403 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 3 }] } };
+    { $caller:tt input = [{ 404 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 4 }] } };
+    { $caller:tt input = [{ 405 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 5 }] } };
+    { $caller:tt input = [{ 406 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 6 }] } };
+    { $caller:tt input = [{ 407 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 7 }] } };
+    { $caller:tt input = [{ 408 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 8 }] } };
+    { $caller:tt input = [{ 409 }] } => { $crate::tt_return! { $caller digits = [{ 4 0 9 }] } };
+    { $caller:tt input = [{ 410 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 0 }] } };
+    { $caller:tt input = [{ 411 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 1 }] } };
+    { $caller:tt input = [{ 412 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 2 }] } };
+    { $caller:tt input = [{ 413 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 3 }] } };
+    { $caller:tt input = [{ 414 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 4 }] } };
+    { $caller:tt input = [{ 415 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 5 }] } };
+    { $caller:tt input = [{ 416 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 6 }] } };
+    { $caller:tt input = [{ 417 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 7 }] } };
+    { $caller:tt input = [{ 418 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 8 }] } };
+    { $caller:tt input = [{ 419 }] } => { $crate::tt_return! { $caller digits = [{ 4 1 9 }] } };
+    { $caller:tt input = [{ 420 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 0 }] } };
+    { $caller:tt input = [{ 421 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 1 }] } };
+    { $caller:tt input = [{ 422 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 2 }] } };
+    { $caller:tt input = [{ 423 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 3 }] } };
+    { $caller:tt input = [{ 424 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 4 }] } };
+    { $caller:tt input = [{ 425 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 5 }] } };
+    { $caller:tt input = [{ 426 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 6 }] } };
+    { $caller:tt input = [{ 427 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 7 }] } };
+    { $caller:tt input = [{ 428 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 8 }] } };
+    { $caller:tt input = [{ 429 }] } => { $crate::tt_return! { $caller digits = [{ 4 2 9 }] } };
+    { $caller:tt input = [{ 430 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 0 }] } };
+    { $caller:tt input = [{ 431 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 1 }] } };
+    { $caller:tt input = [{ 432 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 2 }] } };
+    { $caller:tt input = [{ 433 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 3 }] } };
+    { $caller:tt input = [{ 434 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 4 }] } };
+    { $caller:tt input = [{ 435 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 5 }] } };
+    { $caller:tt input = [{ 436 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 6 }] } };
+    { $caller:tt input = [{ 437 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 7 }] } };
+    { $caller:tt input = [{ 438 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 8 }] } };
+    { $caller:tt input = [{ 439 }] } => { $crate::tt_return! { $caller digits = [{ 4 3 9 }] } };
+    { $caller:tt input = [{ 440 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 0 }] } };
+    { $caller:tt input = [{ 441 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 1 }] } };
+    { $caller:tt input = [{ 442 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 2 }] } };
+    { $caller:tt input = [{ 443 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 3 }] } };
+    { $caller:tt input = [{ 444 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 4 }] } };
+    { $caller:tt input = [{ 445 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 5 }] } };
+    { $caller:tt input = [{ 446 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 6 }] } };
+    { $caller:tt input = [{ 447 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 7 }] } };
+    { $caller:tt input = [{ 448 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 8 }] } };
+    { $caller:tt input = [{ 449 }] } => { $crate::tt_return! { $caller digits = [{ 4 4 9 }] } };
+    { $caller:tt input = [{ 450 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 0 }] } };
+    { $caller:tt input = [{ 451 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 1 }] } };
+    { $caller:tt input = [{ 452 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 2 }] } };
+    { $caller:tt input = [{ 453 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 3 }] } };
+    { $caller:tt input = [{ 454 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 4 }] } };
+    { $caller:tt input = [{ 455 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 5 }] } };
+    { $caller:tt input = [{ 456 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 6 }] } };
+    { $caller:tt input = [{ 457 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 7 }] } };
+    { $caller:tt input = [{ 458 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 8 }] } };
+    { $caller:tt input = [{ 459 }] } => { $crate::tt_return! { $caller digits = [{ 4 5 9 }] } };
+    { $caller:tt input = [{ 460 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 0 }] } };
+    { $caller:tt input = [{ 461 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 1 }] } };
+    { $caller:tt input = [{ 462 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 2 }] } };
+    { $caller:tt input = [{ 463 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 3 }] } };
+    { $caller:tt input = [{ 464 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 4 }] } };
+    { $caller:tt input = [{ 465 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 5 }] } };
+    { $caller:tt input = [{ 466 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 6 }] } };
+    { $caller:tt input = [{ 467 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 7 }] } };
+    { $caller:tt input = [{ 468 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 8 }] } };
+    { $caller:tt input = [{ 469 }] } => { $crate::tt_return! { $caller digits = [{ 4 6 9 }] } };
+    { $caller:tt input = [{ 470 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 0 }] } };
+    { $caller:tt input = [{ 471 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 1 }] } };
+    { $caller:tt input = [{ 472 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 2 }] } };
+    { $caller:tt input = [{ 473 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 3 }] } };
+    { $caller:tt input = [{ 474 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 4 }] } };
+    { $caller:tt input = [{ 475 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 5 }] } };
+    { $caller:tt input = [{ 476 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 6 }] } };
+    { $caller:tt input = [{ 477 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 7 }] } };
+    { $caller:tt input = [{ 478 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 8 }] } };
+    { $caller:tt input = [{ 479 }] } => { $crate::tt_return! { $caller digits = [{ 4 7 9 }] } };
+    { $caller:tt input = [{ 480 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 0 }] } };
+    { $caller:tt input = [{ 481 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 1 }] } };
+    { $caller:tt input = [{ 482 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 2 }] } };
+    { $caller:tt input = [{ 483 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 3 }] } };
+    { $caller:tt input = [{ 484 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 4 }] } };
+    { $caller:tt input = [{ 485 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 5 }] } };
+    { $caller:tt input = [{ 486 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 6 }] } };
+    { $caller:tt input = [{ 487 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 7 }] } };
+    { $caller:tt input = [{ 488 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 8 }] } };
+    { $caller:tt input = [{ 489 }] } => { $crate::tt_return! { $caller digits = [{ 4 8 9 }] } };
+    { $caller:tt input = [{ 490 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 0 }] } };
+    { $caller:tt input = [{ 491 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 1 }] } };
+    { $caller:tt input = [{ 492 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 2 }] } };
+    { $caller:tt input = [{ 493 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 3 }] } };
+    { $caller:tt input = [{ 494 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 4 }] } };
+    { $caller:tt input = [{ 495 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 5 }] } };
+    { $caller:tt input = [{ 496 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 6 }] } };
+    { $caller:tt input = [{ 497 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 7 }] } };
+    { $caller:tt input = [{ 498 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 8 }] } };
+    { $caller:tt input = [{ 499 }] } => { $crate::tt_return! { $caller digits = [{ 4 9 9 }] } };
+    { $caller:tt input = [{ 500 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 0 }] } };
+    { $caller:tt input = [{ 501 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 1 }] } };
+    { $caller:tt input = [{ 502 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 2 }] } };
+    { $caller:tt input = [{ 503 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 3 }] } };
+    { $caller:tt input = [{ 504 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 4 }] } };
+    { $caller:tt input = [{ 505 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 5 }] } };
+    { $caller:tt input = [{ 506 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 6 }] } };
+    { $caller:tt input = [{ 507 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 7 }] } };
+    { $caller:tt input = [{ 508 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 8 }] } };
+    { $caller:tt input = [{ 509 }] } => { $crate::tt_return! { $caller digits = [{ 5 0 9 }] } };
+    { $caller:tt input = [{ 510 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 0 }] } };
+    { $caller:tt input = [{ 511 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 1 }] } };
+    { $caller:tt input = [{ 512 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 2 }] } };
+    { $caller:tt input = [{ 513 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 3 }] } };
+    { $caller:tt input = [{ 514 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 4 }] } };
+    { $caller:tt input = [{ 515 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 5 }] } };
+    { $caller:tt input = [{ 516 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 6 }] } };
+    { $caller:tt input = [{ 517 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 7 }] } };
+    { $caller:tt input = [{ 518 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 8 }] } };
+    { $caller:tt input = [{ 519 }] } => { $crate::tt_return! { $caller digits = [{ 5 1 9 }] } };
+    { $caller:tt input = [{ 520 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 0 }] } };
+    { $caller:tt input = [{ 521 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 1 }] } };
+    { $caller:tt input = [{ 522 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 2 }] } };
+    { $caller:tt input = [{ 523 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 3 }] } };
+    { $caller:tt input = [{ 524 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 4 }] } };
+    { $caller:tt input = [{ 525 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 5 }] } };
+    { $caller:tt input = [{ 526 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 6 }] } };
+    { $caller:tt input = [{ 527 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 7 }] } };
+    { $caller:tt input = [{ 528 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 8 }] } };
+    { $caller:tt input = [{ 529 }] } => { $crate::tt_return! { $caller digits = [{ 5 2 9 }] } };
+    { $caller:tt input = [{ 530 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 0 }] } };
+    { $caller:tt input = [{ 531 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 1 }] } };
+    { $caller:tt input = [{ 532 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 2 }] } };
+    { $caller:tt input = [{ 533 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 3 }] } };
+    { $caller:tt input = [{ 534 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 4 }] } };
+    { $caller:tt input = [{ 535 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 5 }] } };
+    { $caller:tt input = [{ 536 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 6 }] } };
+    { $caller:tt input = [{ 537 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 7 }] } };
+    { $caller:tt input = [{ 538 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 8 }] } };
+    { $caller:tt input = [{ 539 }] } => { $crate::tt_return! { $caller digits = [{ 5 3 9 }] } };
+    { $caller:tt input = [{ 540 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 0 }] } };
+    { $caller:tt input = [{ 541 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 1 }] } };
+    { $caller:tt input = [{ 542 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 2 }] } };
+    { $caller:tt input = [{ 543 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 3 }] } };
+    { $caller:tt input = [{ 544 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 4 }] } };
+    { $caller:tt input = [{ 545 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 5 }] } };
+    { $caller:tt input = [{ 546 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 6 }] } };
+    { $caller:tt input = [{ 547 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 7 }] } };
+    { $caller:tt input = [{ 548 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 8 }] } };
+    { $caller:tt input = [{ 549 }] } => { $crate::tt_return! { $caller digits = [{ 5 4 9 }] } };
+    { $caller:tt input = [{ 550 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 0 }] } };
+    { $caller:tt input = [{ 551 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 1 }] } };
+    { $caller:tt input = [{ 552 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 2 }] } };
+    { $caller:tt input = [{ 553 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 3 }] } };
+    { $caller:tt input = [{ 554 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 4 }] } };
+    { $caller:tt input = [{ 555 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 5 }] } };
+    { $caller:tt input = [{ 556 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 6 }] } };
+    { $caller:tt input = [{ 557 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 7 }] } };
+    { $caller:tt input = [{ 558 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 8 }] } };
+    { $caller:tt input = [{ 559 }] } => { $crate::tt_return! { $caller digits = [{ 5 5 9 }] } };
+    { $caller:tt input = [{ 560 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 0 }] } };
+    { $caller:tt input = [{ 561 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 1 }] } };
+    { $caller:tt input = [{ 562 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 2 }] } };
+    { $caller:tt input = [{ 563 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 3 }] } };
+    { $caller:tt input = [{ 564 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 4 }] } };
+    { $caller:tt input = [{ 565 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 5 }] } };
+    { $caller:tt input = [{ 566 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 6 }] } };
+    { $caller:tt input = [{ 567 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 7 }] } };
+    { $caller:tt input = [{ 568 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 8 }] } };
+    { $caller:tt input = [{ 569 }] } => { $crate::tt_return! { $caller digits = [{ 5 6 9 }] } };
+    { $caller:tt input = [{ 570 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 0 }] } };
+    { $caller:tt input = [{ 571 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 1 }] } };
+    { $caller:tt input = [{ 572 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 2 }] } };
+    { $caller:tt input = [{ 573 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 3 }] } };
+    { $caller:tt input = [{ 574 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 4 }] } };
+    { $caller:tt input = [{ 575 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 5 }] } };
+    { $caller:tt input = [{ 576 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 6 }] } };
+    { $caller:tt input = [{ 577 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 7 }] } };
+    { $caller:tt input = [{ 578 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 8 }] } };
+    { $caller:tt input = [{ 579 }] } => { $crate::tt_return! { $caller digits = [{ 5 7 9 }] } };
+    { $caller:tt input = [{ 580 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 0 }] } };
+    { $caller:tt input = [{ 581 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 1 }] } };
+    { $caller:tt input = [{ 582 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 2 }] } };
+    { $caller:tt input = [{ 583 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 3 }] } };
+    { $caller:tt input = [{ 584 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 4 }] } };
+    { $caller:tt input = [{ 585 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 5 }] } };
+    { $caller:tt input = [{ 586 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 6 }] } };
+    { $caller:tt input = [{ 587 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 7 }] } };
+    { $caller:tt input = [{ 588 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 8 }] } };
+    { $caller:tt input = [{ 589 }] } => { $crate::tt_return! { $caller digits = [{ 5 8 9 }] } };
+    { $caller:tt input = [{ 590 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 0 }] } };
+    { $caller:tt input = [{ 591 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 1 }] } };
+    { $caller:tt input = [{ 592 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 2 }] } };
+    { $caller:tt input = [{ 593 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 3 }] } };
+    { $caller:tt input = [{ 594 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 4 }] } };
+    { $caller:tt input = [{ 595 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 5 }] } };
+    { $caller:tt input = [{ 596 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 6 }] } };
+    { $caller:tt input = [{ 597 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 7 }] } };
+    { $caller:tt input = [{ 598 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 8 }] } };
+    { $caller:tt input = [{ 599 }] } => { $crate::tt_return! { $caller digits = [{ 5 9 9 }] } };
+    { $caller:tt input = [{ 600 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 0 }] } };
+    { $caller:tt input = [{ 601 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 1 }] } };
+    { $caller:tt input = [{ 602 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 2 }] } };
+    { $caller:tt input = [{ 603 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 3 }] } };
+    { $caller:tt input = [{ 604 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 4 }] } };
+    { $caller:tt input = [{ 605 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 5 }] } };
+    { $caller:tt input = [{ 606 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 6 }] } };
+    { $caller:tt input = [{ 607 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 7 }] } };
+    { $caller:tt input = [{ 608 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 8 }] } };
+    { $caller:tt input = [{ 609 }] } => { $crate::tt_return! { $caller digits = [{ 6 0 9 }] } };
+    { $caller:tt input = [{ 610 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 0 }] } };
+    { $caller:tt input = [{ 611 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 1 }] } };
+    { $caller:tt input = [{ 612 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 2 }] } };
+    { $caller:tt input = [{ 613 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 3 }] } };
+    { $caller:tt input = [{ 614 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 4 }] } };
+    { $caller:tt input = [{ 615 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 5 }] } };
+    { $caller:tt input = [{ 616 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 6 }] } };
+    { $caller:tt input = [{ 617 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 7 }] } };
+    { $caller:tt input = [{ 618 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 8 }] } };
+    { $caller:tt input = [{ 619 }] } => { $crate::tt_return! { $caller digits = [{ 6 1 9 }] } };
+    { $caller:tt input = [{ 620 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 0 }] } };
+    { $caller:tt input = [{ 621 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 1 }] } };
+    { $caller:tt input = [{ 622 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 2 }] } };
+    { $caller:tt input = [{ 623 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 3 }] } };
+    { $caller:tt input = [{ 624 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 4 }] } };
+    { $caller:tt input = [{ 625 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 5 }] } };
+    { $caller:tt input = [{ 626 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 6 }] } };
+    { $caller:tt input = [{ 627 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 7 }] } };
+    { $caller:tt input = [{ 628 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 8 }] } };
+    { $caller:tt input = [{ 629 }] } => { $crate::tt_return! { $caller digits = [{ 6 2 9 }] } };
+    { $caller:tt input = [{ 630 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 0 }] } };
+    { $caller:tt input = [{ 631 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 1 }] } };
+    { $caller:tt input = [{ 632 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 2 }] } };
+    { $caller:tt input = [{ 633 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 3 }] } };
+    { $caller:tt input = [{ 634 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 4 }] } };
+    { $caller:tt input = [{ 635 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 5 }] } };
+    { $caller:tt input = [{ 636 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 6 }] } };
+    { $caller:tt input = [{ 637 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 7 }] } };
+    { $caller:tt input = [{ 638 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 8 }] } };
+    { $caller:tt input = [{ 639 }] } => { $crate::tt_return! { $caller digits = [{ 6 3 9 }] } };
+    { $caller:tt input = [{ 640 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 0 }] } };
+    { $caller:tt input = [{ 641 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 1 }] } };
+    { $caller:tt input = [{ 642 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 2 }] } };
+    { $caller:tt input = [{ 643 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 3 }] } };
+    { $caller:tt input = [{ 644 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 4 }] } };
+    { $caller:tt input = [{ 645 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 5 }] } };
+    { $caller:tt input = [{ 646 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 6 }] } };
+    { $caller:tt input = [{ 647 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 7 }] } };
+    { $caller:tt input = [{ 648 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 8 }] } };
+    { $caller:tt input = [{ 649 }] } => { $crate::tt_return! { $caller digits = [{ 6 4 9 }] } };
+    { $caller:tt input = [{ 650 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 0 }] } };
+    { $caller:tt input = [{ 651 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 1 }] } };
+    { $caller:tt input = [{ 652 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 2 }] } };
+    { $caller:tt input = [{ 653 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 3 }] } };
+    { $caller:tt input = [{ 654 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 4 }] } };
+    { $caller:tt input = [{ 655 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 5 }] } };
+    { $caller:tt input = [{ 656 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 6 }] } };
+    { $caller:tt input = [{ 657 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 7 }] } };
+    { $caller:tt input = [{ 658 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 8 }] } };
+    { $caller:tt input = [{ 659 }] } => { $crate::tt_return! { $caller digits = [{ 6 5 9 }] } };
+    { $caller:tt input = [{ 660 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 0 }] } };
+    { $caller:tt input = [{ 661 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 1 }] } };
+    { $caller:tt input = [{ 662 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 2 }] } };
+    { $caller:tt input = [{ 663 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 3 }] } };
+    { $caller:tt input = [{ 664 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 4 }] } };
+    { $caller:tt input = [{ 665 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 5 }] } };
+    { $caller:tt input = [{ 666 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 6 }] } };
+    { $caller:tt input = [{ 667 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 7 }] } };
+    { $caller:tt input = [{ 668 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 8 }] } };
+    { $caller:tt input = [{ 669 }] } => { $crate::tt_return! { $caller digits = [{ 6 6 9 }] } };
+    { $caller:tt input = [{ 670 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 0 }] } };
+    { $caller:tt input = [{ 671 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 1 }] } };
+    { $caller:tt input = [{ 672 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 2 }] } };
+    { $caller:tt input = [{ 673 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 3 }] } };
+    { $caller:tt input = [{ 674 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 4 }] } };
+    { $caller:tt input = [{ 675 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 5 }] } };
+    { $caller:tt input = [{ 676 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 6 }] } };
+    { $caller:tt input = [{ 677 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 7 }] } };
+    { $caller:tt input = [{ 678 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 8 }] } };
+    { $caller:tt input = [{ 679 }] } => { $crate::tt_return! { $caller digits = [{ 6 7 9 }] } };
+    { $caller:tt input = [{ 680 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 0 }] } };
+    { $caller:tt input = [{ 681 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 1 }] } };
+    { $caller:tt input = [{ 682 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 2 }] } };
+    { $caller:tt input = [{ 683 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 3 }] } };
+    { $caller:tt input = [{ 684 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 4 }] } };
+    { $caller:tt input = [{ 685 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 5 }] } };
+    { $caller:tt input = [{ 686 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 6 }] } };
+    { $caller:tt input = [{ 687 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 7 }] } };
+    { $caller:tt input = [{ 688 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 8 }] } };
+    { $caller:tt input = [{ 689 }] } => { $crate::tt_return! { $caller digits = [{ 6 8 9 }] } };
+    { $caller:tt input = [{ 690 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 0 }] } };
+    { $caller:tt input = [{ 691 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 1 }] } };
+    { $caller:tt input = [{ 692 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 2 }] } };
+    { $caller:tt input = [{ 693 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 3 }] } };
+    { $caller:tt input = [{ 694 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 4 }] } };
+    { $caller:tt input = [{ 695 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 5 }] } };
+    { $caller:tt input = [{ 696 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 6 }] } };
+    { $caller:tt input = [{ 697 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 7 }] } };
+    { $caller:tt input = [{ 698 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 8 }] } };
+    { $caller:tt input = [{ 699 }] } => { $crate::tt_return! { $caller digits = [{ 6 9 9 }] } };
+    { $caller:tt input = [{ 700 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 0 }] } };
+    { $caller:tt input = [{ 701 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 1 }] } };
+    { $caller:tt input = [{ 702 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 2 }] } };
+    { $caller:tt input = [{ 703 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 3 }] } };
+    { $caller:tt input = [{ 704 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 4 }] } };
+    { $caller:tt input = [{ 705 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 5 }] } };
+    { $caller:tt input = [{ 706 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 6 }] } };
+    { $caller:tt input = [{ 707 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 7 }] } };
+    { $caller:tt input = [{ 708 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 8 }] } };
+    { $caller:tt input = [{ 709 }] } => { $crate::tt_return! { $caller digits = [{ 7 0 9 }] } };
+    { $caller:tt input = [{ 710 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 0 }] } };
+    { $caller:tt input = [{ 711 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 1 }] } };
+    { $caller:tt input = [{ 712 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 2 }] } };
+    { $caller:tt input = [{ 713 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 3 }] } };
+    { $caller:tt input = [{ 714 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 4 }] } };
+    { $caller:tt input = [{ 715 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 5 }] } };
+    { $caller:tt input = [{ 716 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 6 }] } };
+    { $caller:tt input = [{ 717 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 7 }] } };
+    { $caller:tt input = [{ 718 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 8 }] } };
+    { $caller:tt input = [{ 719 }] } => { $crate::tt_return! { $caller digits = [{ 7 1 9 }] } };
+    { $caller:tt input = [{ 720 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 0 }] } };
+    { $caller:tt input = [{ 721 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 1 }] } };
+    { $caller:tt input = [{ 722 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 2 }] } };
+    { $caller:tt input = [{ 723 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 3 }] } };
+    { $caller:tt input = [{ 724 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 4 }] } };
+    { $caller:tt input = [{ 725 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 5 }] } };
+    { $caller:tt input = [{ 726 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 6 }] } };
+    { $caller:tt input = [{ 727 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 7 }] } };
+    { $caller:tt input = [{ 728 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 8 }] } };
+    { $caller:tt input = [{ 729 }] } => { $crate::tt_return! { $caller digits = [{ 7 2 9 }] } };
+    { $caller:tt input = [{ 730 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 0 }] } };
+    { $caller:tt input = [{ 731 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 1 }] } };
+    { $caller:tt input = [{ 732 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 2 }] } };
+    { $caller:tt input = [{ 733 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 3 }] } };
+    { $caller:tt input = [{ 734 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 4 }] } };
+    { $caller:tt input = [{ 735 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 5 }] } };
+    { $caller:tt input = [{ 736 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 6 }] } };
+    { $caller:tt input = [{ 737 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 7 }] } };
+    { $caller:tt input = [{ 738 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 8 }] } };
+    { $caller:tt input = [{ 739 }] } => { $crate::tt_return! { $caller digits = [{ 7 3 9 }] } };
+    { $caller:tt input = [{ 740 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 0 }] } };
+    { $caller:tt input = [{ 741 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 1 }] } };
+    { $caller:tt input = [{ 742 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 2 }] } };
+    { $caller:tt input = [{ 743 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 3 }] } };
+    { $caller:tt input = [{ 744 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 4 }] } };
+    { $caller:tt input = [{ 745 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 5 }] } };
+    { $caller:tt input = [{ 746 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 6 }] } };
+    { $caller:tt input = [{ 747 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 7 }] } };
+    { $caller:tt input = [{ 748 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 8 }] } };
+    { $caller:tt input = [{ 749 }] } => { $crate::tt_return! { $caller digits = [{ 7 4 9 }] } };
+    { $caller:tt input = [{ 750 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 0 }] } };
+    { $caller:tt input = [{ 751 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 1 }] } };
+    { $caller:tt input = [{ 752 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 2 }] } };
+    { $caller:tt input = [{ 753 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 3 }] } };
+    { $caller:tt input = [{ 754 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 4 }] } };
+    { $caller:tt input = [{ 755 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 5 }] } };
+    { $caller:tt input = [{ 756 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 6 }] } };
+    { $caller:tt input = [{ 757 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 7 }] } };
+    { $caller:tt input = [{ 758 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 8 }] } };
+    { $caller:tt input = [{ 759 }] } => { $crate::tt_return! { $caller digits = [{ 7 5 9 }] } };
+    { $caller:tt input = [{ 760 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 0 }] } };
+    { $caller:tt input = [{ 761 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 1 }] } };
+    { $caller:tt input = [{ 762 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 2 }] } };
+    { $caller:tt input = [{ 763 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 3 }] } };
+    { $caller:tt input = [{ 764 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 4 }] } };
+    { $caller:tt input = [{ 765 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 5 }] } };
+    { $caller:tt input = [{ 766 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 6 }] } };
+    { $caller:tt input = [{ 767 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 7 }] } };
+    { $caller:tt input = [{ 768 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 8 }] } };
+    { $caller:tt input = [{ 769 }] } => { $crate::tt_return! { $caller digits = [{ 7 6 9 }] } };
+    { $caller:tt input = [{ 770 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 0 }] } };
+    { $caller:tt input = [{ 771 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 1 }] } };
+    { $caller:tt input = [{ 772 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 2 }] } };
+    { $caller:tt input = [{ 773 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 3 }] } };
+    { $caller:tt input = [{ 774 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 4 }] } };
+    { $caller:tt input = [{ 775 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 5 }] } };
+    { $caller:tt input = [{ 776 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 6 }] } };
+    { $caller:tt input = [{ 777 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 7 }] } };
+    { $caller:tt input = [{ 778 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 8 }] } };
+    { $caller:tt input = [{ 779 }] } => { $crate::tt_return! { $caller digits = [{ 7 7 9 }] } };
+    { $caller:tt input = [{ 780 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 0 }] } };
+    { $caller:tt input = [{ 781 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 1 }] } };
+    { $caller:tt input = [{ 782 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 2 }] } };
+    { $caller:tt input = [{ 783 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 3 }] } };
+    { $caller:tt input = [{ 784 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 4 }] } };
+    { $caller:tt input = [{ 785 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 5 }] } };
+    { $caller:tt input = [{ 786 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 6 }] } };
+    { $caller:tt input = [{ 787 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 7 }] } };
+    { $caller:tt input = [{ 788 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 8 }] } };
+    { $caller:tt input = [{ 789 }] } => { $crate::tt_return! { $caller digits = [{ 7 8 9 }] } };
+    { $caller:tt input = [{ 790 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 0 }] } };
+    { $caller:tt input = [{ 791 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 1 }] } };
+    { $caller:tt input = [{ 792 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 2 }] } };
+    { $caller:tt input = [{ 793 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 3 }] } };
+    { $caller:tt input = [{ 794 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 4 }] } };
+    { $caller:tt input = [{ 795 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 5 }] } };
+    { $caller:tt input = [{ 796 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 6 }] } };
+    { $caller:tt input = [{ 797 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 7 }] } };
+    { $caller:tt input = [{ 798 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 8 }] } };
+    { $caller:tt input = [{ 799 }] } => { $crate::tt_return! { $caller digits = [{ 7 9 9 }] } };
+    { $caller:tt input = [{ 800 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 0 }] } };
+    { $caller:tt input = [{ 801 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 1 }] } };
+    { $caller:tt input = [{ 802 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 2 }] } };
+    { $caller:tt input = [{ 803 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 3 }] } };
+    { $caller:tt input = [{ 804 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 4 }] } };
+    { $caller:tt input = [{ 805 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 5 }] } };
+    { $caller:tt input = [{ 806 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 6 }] } };
+    { $caller:tt input = [{ 807 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 7 }] } };
+    { $caller:tt input = [{ 808 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 8 }] } };
+    { $caller:tt input = [{ 809 }] } => { $crate::tt_return! { $caller digits = [{ 8 0 9 }] } };
+    { $caller:tt input = [{ 810 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 0 }] } };
+    { $caller:tt input = [{ 811 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 1 }] } };
+    { $caller:tt input = [{ 812 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 2 }] } };
+    { $caller:tt input = [{ 813 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 3 }] } };
+    { $caller:tt input = [{ 814 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 4 }] } };
+    { $caller:tt input = [{ 815 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 5 }] } };
+    { $caller:tt input = [{ 816 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 6 }] } };
+    { $caller:tt input = [{ 817 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 7 }] } };
+    { $caller:tt input = [{ 818 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 8 }] } };
+    { $caller:tt input = [{ 819 }] } => { $crate::tt_return! { $caller digits = [{ 8 1 9 }] } };
+    { $caller:tt input = [{ 820 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 0 }] } };
+    { $caller:tt input = [{ 821 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 1 }] } };
+    { $caller:tt input = [{ 822 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 2 }] } };
+    { $caller:tt input = [{ 823 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 3 }] } };
+    { $caller:tt input = [{ 824 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 4 }] } };
+    { $caller:tt input = [{ 825 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 5 }] } };
+    { $caller:tt input = [{ 826 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 6 }] } };
+    { $caller:tt input = [{ 827 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 7 }] } };
+    { $caller:tt input = [{ 828 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 8 }] } };
+    { $caller:tt input = [{ 829 }] } => { $crate::tt_return! { $caller digits = [{ 8 2 9 }] } };
+    { $caller:tt input = [{ 830 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 0 }] } };
+    { $caller:tt input = [{ 831 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 1 }] } };
+    { $caller:tt input = [{ 832 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 2 }] } };
+    { $caller:tt input = [{ 833 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 3 }] } };
+    { $caller:tt input = [{ 834 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 4 }] } };
+    { $caller:tt input = [{ 835 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 5 }] } };
+    { $caller:tt input = [{ 836 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 6 }] } };
+    { $caller:tt input = [{ 837 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 7 }] } };
+    { $caller:tt input = [{ 838 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 8 }] } };
+    { $caller:tt input = [{ 839 }] } => { $crate::tt_return! { $caller digits = [{ 8 3 9 }] } };
+    { $caller:tt input = [{ 840 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 0 }] } };
+    { $caller:tt input = [{ 841 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 1 }] } };
+    { $caller:tt input = [{ 842 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 2 }] } };
+    { $caller:tt input = [{ 843 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 3 }] } };
+    { $caller:tt input = [{ 844 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 4 }] } };
+    { $caller:tt input = [{ 845 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 5 }] } };
+    { $caller:tt input = [{ 846 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 6 }] } };
+    { $caller:tt input = [{ 847 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 7 }] } };
+    { $caller:tt input = [{ 848 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 8 }] } };
+    { $caller:tt input = [{ 849 }] } => { $crate::tt_return! { $caller digits = [{ 8 4 9 }] } };
+    { $caller:tt input = [{ 850 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 0 }] } };
+    { $caller:tt input = [{ 851 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 1 }] } };
+    { $caller:tt input = [{ 852 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 2 }] } };
+    { $caller:tt input = [{ 853 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 3 }] } };
+    { $caller:tt input = [{ 854 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 4 }] } };
+    { $caller:tt input = [{ 855 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 5 }] } };
+    { $caller:tt input = [{ 856 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 6 }] } };
+    { $caller:tt input = [{ 857 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 7 }] } };
+    { $caller:tt input = [{ 858 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 8 }] } };
+    { $caller:tt input = [{ 859 }] } => { $crate::tt_return! { $caller digits = [{ 8 5 9 }] } };
+    { $caller:tt input = [{ 860 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 0 }] } };
+    { $caller:tt input = [{ 861 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 1 }] } };
+    { $caller:tt input = [{ 862 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 2 }] } };
+    { $caller:tt input = [{ 863 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 3 }] } };
+    { $caller:tt input = [{ 864 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 4 }] } };
+    { $caller:tt input = [{ 865 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 5 }] } };
+    { $caller:tt input = [{ 866 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 6 }] } };
+    { $caller:tt input = [{ 867 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 7 }] } };
+    { $caller:tt input = [{ 868 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 8 }] } };
+    { $caller:tt input = [{ 869 }] } => { $crate::tt_return! { $caller digits = [{ 8 6 9 }] } };
+    { $caller:tt input = [{ 870 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 0 }] } };
+    { $caller:tt input = [{ 871 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 1 }] } };
+    { $caller:tt input = [{ 872 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 2 }] } };
+    { $caller:tt input = [{ 873 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 3 }] } };
+    { $caller:tt input = [{ 874 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 4 }] } };
+    { $caller:tt input = [{ 875 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 5 }] } };
+    { $caller:tt input = [{ 876 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 6 }] } };
+    { $caller:tt input = [{ 877 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 7 }] } };
+    { $caller:tt input = [{ 878 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 8 }] } };
+    { $caller:tt input = [{ 879 }] } => { $crate::tt_return! { $caller digits = [{ 8 7 9 }] } };
+    { $caller:tt input = [{ 880 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 0 }] } };
+    { $caller:tt input = [{ 881 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 1 }] } };
+    { $caller:tt input = [{ 882 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 2 }] } };
+    { $caller:tt input = [{ 883 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 3 }] } };
+    { $caller:tt input = [{ 884 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 4 }] } };
+    { $caller:tt input = [{ 885 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 5 }] } };
+    { $caller:tt input = [{ 886 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 6 }] } };
+    { $caller:tt input = [{ 887 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 7 }] } };
+    { $caller:tt input = [{ 888 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 8 }] } };
+    { $caller:tt input = [{ 889 }] } => { $crate::tt_return! { $caller digits = [{ 8 8 9 }] } };
+    { $caller:tt input = [{ 890 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 0 }] } };
+    { $caller:tt input = [{ 891 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 1 }] } };
+    { $caller:tt input = [{ 892 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 2 }] } };
+    { $caller:tt input = [{ 893 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 3 }] } };
+    { $caller:tt input = [{ 894 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 4 }] } };
+    { $caller:tt input = [{ 895 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 5 }] } };
+    { $caller:tt input = [{ 896 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 6 }] } };
+    { $caller:tt input = [{ 897 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 7 }] } };
+    { $caller:tt input = [{ 898 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 8 }] } };
+    { $caller:tt input = [{ 899 }] } => { $crate::tt_return! { $caller digits = [{ 8 9 9 }] } };
+    { $caller:tt input = [{ 900 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 0 }] } };
+    { $caller:tt input = [{ 901 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 1 }] } };
+    { $caller:tt input = [{ 902 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 2 }] } };
+    { $caller:tt input = [{ 903 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 3 }] } };
+    { $caller:tt input = [{ 904 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 4 }] } };
+    { $caller:tt input = [{ 905 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 5 }] } };
+    { $caller:tt input = [{ 906 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 6 }] } };
+    { $caller:tt input = [{ 907 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 7 }] } };
+    { $caller:tt input = [{ 908 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 8 }] } };
+    { $caller:tt input = [{ 909 }] } => { $crate::tt_return! { $caller digits = [{ 9 0 9 }] } };
+    { $caller:tt input = [{ 910 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 0 }] } };
+    { $caller:tt input = [{ 911 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 1 }] } };
+    { $caller:tt input = [{ 912 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 2 }] } };
+    { $caller:tt input = [{ 913 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 3 }] } };
+    { $caller:tt input = [{ 914 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 4 }] } };
+    { $caller:tt input = [{ 915 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 5 }] } };
+    { $caller:tt input = [{ 916 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 6 }] } };
+    { $caller:tt input = [{ 917 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 7 }] } };
+    { $caller:tt input = [{ 918 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 8 }] } };
+    { $caller:tt input = [{ 919 }] } => { $crate::tt_return! { $caller digits = [{ 9 1 9 }] } };
+    { $caller:tt input = [{ 920 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 0 }] } };
+    { $caller:tt input = [{ 921 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 1 }] } };
+    { $caller:tt input = [{ 922 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 2 }] } };
+    { $caller:tt input = [{ 923 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 3 }] } };
+    { $caller:tt input = [{ 924 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 4 }] } };
+    { $caller:tt input = [{ 925 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 5 }] } };
+    { $caller:tt input = [{ 926 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 6 }] } };
+    { $caller:tt input = [{ 927 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 7 }] } };
+    { $caller:tt input = [{ 928 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 8 }] } };
+    { $caller:tt input = [{ 929 }] } => { $crate::tt_return! { $caller digits = [{ 9 2 9 }] } };
+    { $caller:tt input = [{ 930 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 0 }] } };
+    { $caller:tt input = [{ 931 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 1 }] } };
+    { $caller:tt input = [{ 932 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 2 }] } };
+    { $caller:tt input = [{ 933 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 3 }] } };
+    { $caller:tt input = [{ 934 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 4 }] } };
+    { $caller:tt input = [{ 935 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 5 }] } };
+    { $caller:tt input = [{ 936 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 6 }] } };
+    { $caller:tt input = [{ 937 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 7 }] } };
+    { $caller:tt input = [{ 938 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 8 }] } };
+    { $caller:tt input = [{ 939 }] } => { $crate::tt_return! { $caller digits = [{ 9 3 9 }] } };
+    { $caller:tt input = [{ 940 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 0 }] } };
+    { $caller:tt input = [{ 941 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 1 }] } };
+    { $caller:tt input = [{ 942 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 2 }] } };
+    { $caller:tt input = [{ 943 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 3 }] } };
+    { $caller:tt input = [{ 944 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 4 }] } };
+    { $caller:tt input = [{ 945 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 5 }] } };
+    { $caller:tt input = [{ 946 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 6 }] } };
+    { $caller:tt input = [{ 947 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 7 }] } };
+    { $caller:tt input = [{ 948 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 8 }] } };
+    { $caller:tt input = [{ 949 }] } => { $crate::tt_return! { $caller digits = [{ 9 4 9 }] } };
+    { $caller:tt input = [{ 950 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 0 }] } };
+    { $caller:tt input = [{ 951 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 1 }] } };
+    { $caller:tt input = [{ 952 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 2 }] } };
+    { $caller:tt input = [{ 953 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 3 }] } };
+    { $caller:tt input = [{ 954 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 4 }] } };
+    { $caller:tt input = [{ 955 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 5 }] } };
+    { $caller:tt input = [{ 956 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 6 }] } };
+    { $caller:tt input = [{ 957 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 7 }] } };
+    { $caller:tt input = [{ 958 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 8 }] } };
+    { $caller:tt input = [{ 959 }] } => { $crate::tt_return! { $caller digits = [{ 9 5 9 }] } };
+    { $caller:tt input = [{ 960 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 0 }] } };
+    { $caller:tt input = [{ 961 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 1 }] } };
+    { $caller:tt input = [{ 962 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 2 }] } };
+    { $caller:tt input = [{ 963 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 3 }] } };
+    { $caller:tt input = [{ 964 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 4 }] } };
+    { $caller:tt input = [{ 965 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 5 }] } };
+    { $caller:tt input = [{ 966 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 6 }] } };
+    { $caller:tt input = [{ 967 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 7 }] } };
+    { $caller:tt input = [{ 968 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 8 }] } };
+    { $caller:tt input = [{ 969 }] } => { $crate::tt_return! { $caller digits = [{ 9 6 9 }] } };
+    { $caller:tt input = [{ 970 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 0 }] } };
+    { $caller:tt input = [{ 971 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 1 }] } };
+    { $caller:tt input = [{ 972 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 2 }] } };
+    { $caller:tt input = [{ 973 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 3 }] } };
+    { $caller:tt input = [{ 974 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 4 }] } };
+    { $caller:tt input = [{ 975 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 5 }] } };
+    { $caller:tt input = [{ 976 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 6 }] } };
+    { $caller:tt input = [{ 977 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 7 }] } };
+    { $caller:tt input = [{ 978 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 8 }] } };
+    { $caller:tt input = [{ 979 }] } => { $crate::tt_return! { $caller digits = [{ 9 7 9 }] } };
+    { $caller:tt input = [{ 980 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 0 }] } };
+    { $caller:tt input = [{ 981 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 1 }] } };
+    { $caller:tt input = [{ 982 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 2 }] } };
+    { $caller:tt input = [{ 983 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 3 }] } };
+    { $caller:tt input = [{ 984 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 4 }] } };
+    { $caller:tt input = [{ 985 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 5 }] } };
+    { $caller:tt input = [{ 986 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 6 }] } };
+    { $caller:tt input = [{ 987 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 7 }] } };
+    { $caller:tt input = [{ 988 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 8 }] } };
+    { $caller:tt input = [{ 989 }] } => { $crate::tt_return! { $caller digits = [{ 9 8 9 }] } };
+    { $caller:tt input = [{ 990 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 0 }] } };
+    { $caller:tt input = [{ 991 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 1 }] } };
+    { $caller:tt input = [{ 992 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 2 }] } };
+    { $caller:tt input = [{ 993 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 3 }] } };
+    { $caller:tt input = [{ 994 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 4 }] } };
+    { $caller:tt input = [{ 995 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 5 }] } };
+    { $caller:tt input = [{ 996 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 6 }] } };
+    { $caller:tt input = [{ 997 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 7 }] } };
+    { $caller:tt input = [{ 998 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 8 }] } };
+    { $caller:tt input = [{ 999 }] } => { $crate::tt_return! { $caller digits = [{ 9 9 9 }] } };
+
+    // Unexpected: not an integer literal from 0 to 999. Returns no digits so
+    // that the caller can report the range under its own name for the input.
+    {
+        $caller:tt
+        input = [{ $($unexpected:tt)* }]
+    } => {
+        $crate::tt_return! {
+            $caller
+            digits = [{ }]
+        }
+    };
+}
+
+// Conversion in the other direction, for outputs like a count of replacements
+// that are accumulated in unary but more pleasant to receive as a number.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_literal {
+    { $caller:tt unary = [{ }] } => { $crate::tt_return! { $caller literal = [{ 0 }] } };
+    { $caller:tt unary = [{ 1 }] } => { $crate::tt_return! { $caller literal = [{ 1 }] } };
+    { $caller:tt unary = [{ 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 2 }] } };
+    { $caller:tt unary = [{ 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 3 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 4 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 5 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 6 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 7 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 8 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 9 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 10 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 11 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 12 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 13 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 14 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 15 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 16 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 17 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 18 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 19 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 20 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 21 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 22 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 23 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 24 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 25 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 26 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 27 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 28 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 29 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 30 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 31 }] } };
+    { $caller:tt unary = [{ 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 }] } => { $crate::tt_return! { $caller literal = [{ 32 }] } };
+
+    // Unexpected: count is larger than the supported range.
+    {
+        $caller:tt
+        unary = [{ $($unexpected:tt)* }]
+    } => {
+        $crate::error_unexpected_last! {
+            $($unexpected)*
+        }
+    };
+}
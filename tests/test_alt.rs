@@ -0,0 +1,126 @@
+use tt_call::{parse_type, tt_alt, tt_call, tt_eq, tt_is_ident, tt_is_lifetime, tt_return};
+
+macro_rules! parse_lifetime {
+    {
+        $caller:tt
+        input = [{ $lifetime:lifetime $($rest:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            lifetime = [{ $lifetime }]
+            rest = [{ $($rest)* }]
+        }
+    };
+}
+
+macro_rules! parse_nothing {
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            nothing = [{ $($input)* }]
+        }
+    };
+}
+
+macro_rules! describe {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_alt }]
+            branch = [{ tt_is_lifetime => parse_lifetime }]
+            branch = [{ tt_is_ident => parse_type }]
+            otherwise = [{ parse_nothing }]
+            input = [{ $($input)* }]
+            ~~> describe_return
+        }
+    };
+}
+
+macro_rules! describe_with_args {
+    ($($input:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_alt }]
+            branch = [{ tt_eq(expected = [{ 'static }]) => parse_nothing }]
+            branch = [{ tt_is_lifetime => parse_lifetime }]
+            input = [{ $($input)* }]
+            ~~> describe_return
+        }
+    }};
+}
+
+macro_rules! describe_return {
+    {
+        lifetime = [{ $lifetime:lifetime }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        ("lifetime", squash(stringify!($lifetime)), squash(stringify!($($rest)*)))
+    };
+
+    {
+        type = [{ $($ty:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        ("type", squash(stringify!($($ty)*)), squash(stringify!($($rest)*)))
+    };
+
+    {
+        nothing = [{ $($input:tt)* }]
+    } => {
+        ("nothing", squash(stringify!($($input)*)), String::new())
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_first_branch() {
+    assert_eq!(
+        describe!('a + Send),
+        ("lifetime", "'a".into(), "+Send".into())
+    );
+}
+
+#[test]
+fn test_later_branch() {
+    assert_eq!(
+        describe!(Vec<u8>, x),
+        ("type", "Vec<u8>".into(), ",x".into())
+    );
+}
+
+#[test]
+fn test_otherwise() {
+    assert_eq!(describe!(+ 'a), ("nothing", "+'a".into(), "".into()));
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(describe!(), ("nothing", "".into(), "".into()));
+}
+
+#[test]
+fn test_nested_group() {
+    // A group is not an identifier or lifetime, so it reaches the otherwise
+    // parser as a whole.
+    assert_eq!(
+        describe!((a, [b])),
+        ("nothing", "(a,[b])".into(), "".into())
+    );
+}
+
+#[test]
+fn test_condition_args() {
+    assert_eq!(
+        describe_with_args!('static),
+        ("nothing", "'static".into(), "".into())
+    );
+    assert_eq!(
+        describe_with_args!('a),
+        ("lifetime", "'a".into(), "".into())
+    );
+}
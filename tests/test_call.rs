@@ -0,0 +1,144 @@
+use tt_call::{parse_type, tt_call, tt_is_comma, tt_is_ident, tt_return, tt_take_until};
+
+macro_rules! echo {
+    {
+        $caller:tt
+        $key:ident = [{ $($value:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            value = [{ $($value)* }]
+        }
+    };
+}
+
+#[test]
+fn test_select_position_type() {
+    let value: tt_call! {
+        macro = [{ parse_type }]
+        input = [{ u16 }]
+        ~~> select[1]
+    } = 5;
+    assert_eq!(value, 5u16);
+}
+
+#[test]
+fn test_select_position_expr() {
+    let sum = tt_call! {
+        macro = [{ tt_take_until }]
+        condition = [{ tt_is_comma }]
+        input = [{ 1 + 2, 3 }]
+        ~~> select[1]
+    };
+    assert_eq!(sum, 3);
+}
+
+#[test]
+fn test_first_input_named_output() {
+    let value = tt_call! {
+        macro = [{ echo }]
+        output = [{ 1 }]
+    };
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_first_input_named_expect() {
+    let value = tt_call! {
+        macro = [{ echo }]
+        expect = [{ 1 }]
+    };
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_first_input_named_budget() {
+    let value = tt_call! {
+        macro = [{ echo }]
+        budget = [{ 1 }]
+    };
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_expect() {
+    let value = {
+        {
+            tt_call! {
+                macro = [{ echo }]
+                expect = [{ value }]
+                input = [{ 1 }]
+            }
+        }
+    };
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_expect_with_budget() {
+    let value = {
+        {
+            tt_call! {
+                macro = [{ echo }]
+                budget(8)
+                expect = [{ value }]
+                input = [{ 1 }]
+            }
+        }
+    };
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_select_all() {
+    let x = 3;
+    let product = tt_call! {
+        macro = [{ tt_take_until }]
+        condition = [{ tt_is_ident }]
+        input = [{ 2 * x }]
+        ~~> select[..]
+    };
+    assert_eq!(product, 6);
+}
+
+#[test]
+fn test_select_all_type() {
+    let value: tt_call! {
+        macro = [{ parse_type }]
+        input = [{ u16 }]
+        ~~> select[..]
+    } = 5;
+    assert_eq!(value, 5u16);
+}
+
+#[test]
+fn test_budget_range() {
+    // The macro makes no calls of its own, so even no budget is enough.
+    let none = tt_call! {
+        macro = [{ echo }]
+        budget(0)
+        input = [{ 1 }]
+    };
+    let most = tt_call! {
+        macro = [{ echo }]
+        budget(999)
+        input = [{ 2 }]
+    };
+    assert_eq!((none, most), (1, 2));
+}
+
+#[test]
+fn test_budget_borrow() {
+    // Budgets whose last digits run out before the ones before them.
+    let tens: tt_call! {
+        macro = [{ parse_type }]
+        budget(50)
+        input = [{ Vec<Option<u8>> }]
+    } = vec![Some(1)];
+    let hundreds: tt_call! {
+        macro = [{ parse_type }]
+        budget(101)
+        input = [{ Vec<Option<u8>> }]
+    } = vec![Some(2)];
+    assert_eq!(tens.len() + hundreds.len(), 2);
+}
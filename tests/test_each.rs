@@ -0,0 +1,117 @@
+use tt_call::{parse_type, tt_call_each, tt_return};
+
+macro_rules! wrap {
+    {
+        $caller:tt
+        $(open = [{ $open:tt }])*
+        input = [{ $($element:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            wrapped = [{ $($open)* ( $($element)* ) }]
+        }
+    };
+}
+
+macro_rules! wrap_each {
+    ($([$($element:tt)*])*) => {
+        tt_call_each! {
+            macro = [{ wrap }]
+            each = [{ $( [{ input = [{ $($element)* }] }] )* }]
+            ~~> wrap_each_return
+        }
+    };
+}
+
+macro_rules! wrap_each_with {
+    ($open:tt $([$($element:tt)*])*) => {
+        tt_call_each! {
+            macro = [{ wrap }]
+            each = [{ $( [{ input = [{ $($element)* }] }] )* }]
+            open = [{ $open }]
+            ~~> wrap_each_return
+        }
+    };
+}
+
+macro_rules! wrap_each_return {
+    {
+        $(
+            result = [{ wrapped = [{ $($wrapped:tt)* }] }]
+        )*
+    } => {
+        vec![$( squash(stringify!($($wrapped)*)) ),*] as Vec<String>
+    };
+}
+
+macro_rules! types {
+    ($([$($element:tt)*])*) => {
+        tt_call_each! {
+            macro = [{ parse_type }]
+            each = [{ $( [{ input = [{ $($element)* }] }] )* }]
+            ~~> types_return! {
+                label = [{ "types" }]
+            }
+        }
+    };
+}
+
+macro_rules! types_return {
+    {
+        label = [{ $label:expr }]
+        $(
+            result = [{ type = [{ $($ty:tt)* }] rest = [{ $($rest:tt)* }] }]
+        )*
+    } => {
+        (
+            $label,
+            vec![$( (squash(stringify!($($ty)*)), squash(stringify!($($rest)*))) ),*]
+                as Vec<(String, String)>,
+        )
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_each() {
+    assert_eq!(wrap_each!([a] [b c]), ["(a)", "(bc)"]);
+}
+
+#[test]
+fn test_empty_input() {
+    assert!(wrap_each!().is_empty());
+}
+
+#[test]
+fn test_empty_block_input() {
+    assert_eq!(wrap_each!([] [x] []), ["()", "(x)", "()"]);
+}
+
+#[test]
+fn test_nested_group() {
+    // Groups inside of a block are passed to the macro untouched.
+    assert_eq!(wrap_each! { [[{ a }] (b, [c])] }, ["([{a}](b,[c]))"]);
+}
+
+#[test]
+fn test_shared_args() {
+    assert_eq!(wrap_each_with! { & [a] [b] }, ["&(a)", "&(b)"]);
+}
+
+#[test]
+fn test_multiple_outputs() {
+    assert_eq!(
+        types!([Vec<u8>] [&'a str, x]),
+        (
+            "types",
+            vec![
+                ("Vec<u8>".to_owned(), "".to_owned()),
+                ("&'astr".to_owned(), ",x".to_owned()),
+            ],
+        ),
+    );
+}
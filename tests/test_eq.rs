@@ -0,0 +1,62 @@
+use tt_call::{tt_call, tt_eq};
+
+macro_rules! same_token {
+    ($expected:tt $input:tt) => {{
+        tt_call! {
+            macro = [{ tt_eq }]
+            expected = [{ $expected }]
+            input = [{ $input }]
+        }
+    }};
+}
+
+#[test]
+fn test_ident() {
+    assert!(same_token!(foo foo));
+    assert!(!same_token!(foo bar));
+}
+
+#[test]
+fn test_keyword() {
+    assert!(same_token!(self self));
+    assert!(!same_token!(self Self));
+}
+
+#[test]
+fn test_lifetime() {
+    assert!(same_token!('a 'a));
+    assert!(!same_token!('a 'b));
+}
+
+#[test]
+fn test_literal() {
+    assert!(same_token!("x" "x"));
+    assert!(!same_token!(1 1.0));
+}
+
+#[test]
+fn test_punct() {
+    assert!(same_token!(=> =>));
+    assert!(same_token!(:: ::));
+    assert!(!same_token!(=> =));
+}
+
+#[test]
+fn test_dollar() {
+    // The dollar sign is also used for the metavariables of the helper macro.
+    assert!(same_token!($ $));
+    assert!(!same_token!($ x));
+}
+
+#[test]
+fn test_group() {
+    assert!(same_token!((a, [b])(a, [b])));
+    assert!(!same_token!((a, [b])(a, [c])));
+    assert!(!same_token!((a)[a]));
+}
+
+#[test]
+fn test_empty_group() {
+    assert!(same_token!({} {}));
+    assert!(!same_token!({}()));
+}
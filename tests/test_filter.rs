@@ -0,0 +1,100 @@
+use tt_call::{tt_call, tt_eq, tt_filter, tt_is_comma, tt_is_ident, tt_partition};
+
+macro_rules! idents {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_filter }]
+            condition = [{ tt_is_ident }]
+            input = [{ $($input)* }]
+            ~~> filter_return
+        }
+    };
+}
+
+macro_rules! without_commas {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_partition }]
+            condition = [{ tt_is_comma }]
+            input = [{ $($input)* }]
+            ~~> partition_return
+        }
+    };
+}
+
+macro_rules! only_self {
+    ($($input:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_filter }]
+            condition = [{ tt_eq }]
+            condition_args = [{ expected = [{ self }] }]
+            input = [{ $($input)* }]
+            ~~> filter_return
+        }
+    }};
+}
+
+macro_rules! filter_return {
+    {
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        squash(stringify!($($tokens)*))
+    };
+}
+
+macro_rules! partition_return {
+    {
+        true = [{ $($true:tt)* }]
+        false = [{ $($false:tt)* }]
+    } => {
+        (squash(stringify!($($true)*)), squash(stringify!($($false)*)))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_filter() {
+    assert_eq!(idents!("a" b 'c' d), "bd");
+}
+
+#[test]
+fn test_filter_empty_input() {
+    assert_eq!(idents!(), "");
+}
+
+#[test]
+fn test_filter_nested_group() {
+    // Groups are dropped as a whole, including the identifiers inside.
+    assert_eq!(idents!(a (b, [c]) d { e }), "ad");
+}
+
+#[test]
+fn test_filter_condition_args() {
+    assert_eq!(only_self!(self x self (self)), "selfself");
+}
+
+#[test]
+fn test_partition() {
+    assert_eq!(
+        without_commas!(a, b, c),
+        (",,".to_owned(), "abc".to_owned()),
+    );
+}
+
+#[test]
+fn test_partition_empty_input() {
+    assert_eq!(without_commas!(), ("".to_owned(), "".to_owned()));
+}
+
+#[test]
+fn test_partition_nested_group() {
+    // The comma inside the group is not looked at.
+    assert_eq!(
+        without_commas!((a, b), c),
+        (",".to_owned(), "(a,b)c".to_owned()),
+    );
+}
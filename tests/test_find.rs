@@ -0,0 +1,133 @@
+use tt_call::{tt_call, tt_eq, tt_find, tt_find_path, tt_is_comma, tt_is_lifetime};
+
+macro_rules! find_comma {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_find }]
+            condition = [{ tt_is_comma }]
+            input = [{ $($input)* }]
+            ~~> find_return
+        }
+    };
+}
+
+macro_rules! find_return {
+    {
+        before = [{ $($before:tt)* }]
+        found = [{ $($found:tt)* }]
+        after = [{ $($after:tt)* }]
+    } => {
+        (
+            squash(stringify!($($before)*)),
+            squash(stringify!($($found)*)),
+            squash(stringify!($($after)*)),
+        )
+    };
+}
+
+macro_rules! lifetime_path {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_find_path }]
+            condition = [{ tt_is_lifetime }]
+            input = [{ $($input)* }]
+            ~~> path_return
+        }
+    };
+}
+
+macro_rules! arrow_path {
+    ($($input:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_find_path }]
+            condition = [{ tt_eq }]
+            condition_args = [{ expected = [{ => }] }]
+            input = [{ $($input)* }]
+            ~~> path_return
+        }
+    }};
+}
+
+macro_rules! path_return {
+    {
+        path = [{ $( [$($one:tt)*] )* }]
+        found = [{ $($found:tt)* }]
+    } => {
+        (vec![$( 0usize $(+ $one)* ),*] as Vec<usize>, squash(stringify!($($found)*)))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+fn triple(before: &str, found: &str, after: &str) -> (String, String, String) {
+    (before.to_owned(), found.to_owned(), after.to_owned())
+}
+
+#[test]
+fn test_find() {
+    assert_eq!(find_comma!(a b, c, d), triple("ab", ",", "c,d"));
+}
+
+#[test]
+fn test_find_first_token() {
+    assert_eq!(find_comma!(, a), triple("", ",", "a"));
+}
+
+#[test]
+fn test_find_no_match() {
+    assert_eq!(find_comma!(a b), triple("ab", "", ""));
+}
+
+#[test]
+fn test_find_empty_input() {
+    assert_eq!(find_comma!(), triple("", "", ""));
+}
+
+#[test]
+fn test_find_nested_group() {
+    // tt_find does not look inside of groups.
+    assert_eq!(find_comma!((a, b) [c, d]), triple("(a,b)[c,d]", "", ""));
+}
+
+#[test]
+fn test_find_path() {
+    assert_eq!(lifetime_path!(a 'x), (vec![1], "'x".to_owned()));
+}
+
+#[test]
+fn test_find_path_no_match() {
+    assert_eq!(lifetime_path!(a (b) c), (vec![], "".to_owned()));
+}
+
+#[test]
+fn test_find_path_empty_input() {
+    assert_eq!(lifetime_path!(), (vec![], "".to_owned()));
+}
+
+#[test]
+fn test_find_path_nested_group() {
+    assert_eq!(
+        lifetime_path!(a b (c [d 'x] e) 'y),
+        (vec![2, 1, 1], "'x".to_owned()),
+    );
+    assert_eq!(
+        lifetime_path!({ [] () } { { 'z } }),
+        (vec![1, 0, 0], "'z".to_owned())
+    );
+}
+
+#[test]
+fn test_find_path_empty_group() {
+    assert_eq!(lifetime_path!(() [] {} 'a), (vec![3], "'a".to_owned()));
+}
+
+#[test]
+fn test_find_path_condition_args() {
+    assert_eq!(
+        arrow_path! { match x { Some(y) => y } },
+        (vec![2, 2], "=>".to_owned()),
+    );
+}
@@ -0,0 +1,60 @@
+use tt_call::{tt_call, tt_fold, tt_return};
+
+macro_rules! append {
+    {
+        $caller:tt
+        acc = [{ $($acc:tt)* }]
+        element = [{ $($element:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            joined = [{ $($acc)* ; $($element)* }]
+        }
+    };
+}
+
+macro_rules! join {
+    (($($initial:tt)*) $([$($element:tt)*])*) => {
+        tt_call! {
+            macro = [{ tt_fold }]
+            step = [{ append }]
+            initial = [{ $($initial)* }]
+            input = [{ $( [{ $($element)* }] )* }]
+            ~~> join_return
+        }
+    };
+}
+
+macro_rules! join_return {
+    {
+        acc = [{ $($acc:tt)* }]
+    } => {
+        squash(stringify!($($acc)*))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_fold() {
+    assert_eq!(join!((a) [b] [c d]), "a;b;cd");
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(join!((a)), "a");
+}
+
+#[test]
+fn test_empty_initial() {
+    assert_eq!(join!(()), "");
+    assert_eq!(join! { () [x] }, ";x");
+}
+
+#[test]
+fn test_nested_group() {
+    assert_eq!(join! { ((a)) [[{ b }]] [(c, [d])] }, "(a);[{b}];(c,[d])");
+}
@@ -0,0 +1,108 @@
+use tt_call::{parse_type, tt_call, tt_map, tt_return};
+
+macro_rules! wrap {
+    {
+        $caller:tt
+        $(open = [{ $open:tt }])*
+        input = [{ $($element:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            wrapped = [{ $($open)* ( $($element)* ) }]
+        }
+    };
+}
+
+macro_rules! wrap_each {
+    ($([$($element:tt)*])*) => {
+        tt_call! {
+            macro = [{ tt_map }]
+            map = [{ wrap }]
+            input = [{ $( [{ $($element)* }] )* }]
+            ~~> wrap_each_return
+        }
+    };
+}
+
+macro_rules! wrap_each_with {
+    ($open:tt $([$($element:tt)*])*) => {
+        tt_call! {
+            macro = [{ tt_map }]
+            map = [{ wrap }]
+            args = [{ open = [{ $open }] }]
+            input = [{ $( [{ $($element)* }] )* }]
+            ~~> wrap_each_return
+        }
+    };
+}
+
+macro_rules! wrap_each_return {
+    {
+        mapped = [{ $( [{ wrapped = [{ $($wrapped:tt)* }] }] )* }]
+    } => {
+        vec![$( squash(stringify!($($wrapped)*)) ),*] as Vec<String>
+    };
+}
+
+macro_rules! types {
+    ($([$($element:tt)*])*) => {
+        tt_call! {
+            macro = [{ tt_map }]
+            map = [{ parse_type }]
+            input = [{ $( [{ $($element)* }] )* }]
+            ~~> types_return
+        }
+    };
+}
+
+macro_rules! types_return {
+    {
+        mapped = [{ $( [{ type = [{ $($ty:tt)* }] rest = [{ $($rest:tt)* }] }] )* }]
+    } => {
+        vec![$( (squash(stringify!($($ty)*)), squash(stringify!($($rest)*))) ),*]
+            as Vec<(String, String)>
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_map() {
+    assert_eq!(wrap_each!([a] [b c]), ["(a)", "(bc)"]);
+}
+
+#[test]
+fn test_empty_input() {
+    assert!(wrap_each!().is_empty());
+}
+
+#[test]
+fn test_empty_element() {
+    assert_eq!(wrap_each!([] [x] []), ["()", "(x)", "()"]);
+}
+
+#[test]
+fn test_nested_group() {
+    // Only the outer `[{ }]` delimits an element; groups inside it are
+    // passed through untouched.
+    assert_eq!(wrap_each!([[{ a }](b, [c])]), ["([{a}](b,[c]))"]);
+}
+
+#[test]
+fn test_args() {
+    assert_eq!(wrap_each_with!(&[a][b]), ["&(a)", "&(b)"]);
+}
+
+#[test]
+fn test_multiple_outputs() {
+    assert_eq!(
+        types!([Vec<u8>] [&'a str, x]),
+        [
+            ("Vec<u8>".to_owned(), "".to_owned()),
+            ("&'astr".to_owned(), ",x".to_owned()),
+        ],
+    );
+}
@@ -0,0 +1,69 @@
+use tt_call::{tt_call, tt_rename};
+
+macro_rules! rename {
+    ([$($table:tt)*] $($input:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_rename }]
+            rename = [{ $($table)* }]
+            input = [{ $($input)* }]
+            ~~> rename_return
+        }
+    }};
+}
+
+macro_rules! rename_return {
+    {
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        squash(stringify!($($tokens)*))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_rename() {
+    assert_eq!(rename!([a => x, b => y] a + b * c), "x+y*c");
+}
+
+#[test]
+fn test_single_pass() {
+    assert_eq!(rename!([a => b, b => a] a b), "ba");
+}
+
+#[test]
+fn test_keyword() {
+    assert_eq!(rename!([self => __this] self.0), "__this.0");
+}
+
+#[test]
+fn test_trailing_comma() {
+    assert_eq!(rename!([a => x,] a), "x");
+}
+
+#[test]
+fn test_empty_table() {
+    assert_eq!(rename!([] a b), "ab");
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(rename!([a => x]), "");
+}
+
+#[test]
+fn test_nested_group() {
+    assert_eq!(
+        rename!([a => x] a (a [a { a }]) {} () []),
+        "x(x[x{x}]){}()[]",
+    );
+}
+
+#[test]
+fn test_not_ident() {
+    // Only identifiers are renamed, not lifetimes or literals spelled alike.
+    assert_eq!(rename!([a => x] 'a "a" a), "'a\"a\"x");
+}
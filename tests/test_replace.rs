@@ -0,0 +1,67 @@
+#![recursion_limit = "512"]
+
+use tt_call::{tt_call, tt_replace, tt_return};
+
+macro_rules! is_x {
+    {
+        $caller:tt
+        input = [{ x }]
+    } => {
+        tt_return! {
+            $caller
+            is_x = [{ true }]
+        }
+    };
+
+    {
+        $caller:tt
+        input = [{ $other:tt }]
+    } => {
+        tt_return! {
+            $caller
+            is_x = [{ false }]
+        }
+    };
+}
+
+macro_rules! count_return {
+    {
+        tokens = [{ $($tokens:tt)* }]
+        count = [{ $count:literal }]
+    } => {
+        $count
+    };
+}
+
+#[test]
+fn test_count_literal_limit() {
+    let count = tt_call! {
+        macro = [{ tt_replace }]
+        condition = [{ is_x }]
+        replace_with = [{ y }]
+        count = [{ literal }]
+        input = [{
+            x x x x x x x x x x x x x x x x
+            x x x x x x x x x x x x x x x x
+        }]
+        ~~> count_return
+    };
+    assert_eq!(count, 32);
+}
+
+#[test]
+fn test_budget_hundreds() {
+    let count = tt_call! {
+        macro = [{ tt_replace }]
+        budget(192)
+        condition = [{ is_x }]
+        replace_with = [{ y }]
+        count = [{ literal }]
+        input = [{
+            x x x x x x x x x x x x x x x x
+            x x x x x x x x x x x x x x x x
+        }]
+        ~~> count_return
+    };
+    assert_eq!(count, 32);
+}
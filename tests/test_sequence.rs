@@ -0,0 +1,133 @@
+use tt_call::{parse_type, tt_call, tt_return, tt_sequence};
+
+macro_rules! parse_ident {
+    {
+        $caller:tt
+        input = [{ $ident:ident $($rest:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            ident = [{ $ident }]
+            rest = [{ $($rest)* }]
+        }
+    };
+}
+
+macro_rules! parse_group {
+    {
+        $caller:tt
+        input = [{ $group:tt $($rest:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            group = [{ $group }]
+            rest = [{ $($rest)* }]
+        }
+    };
+}
+
+macro_rules! no_steps {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_sequence }]
+            input = [{ $($input)* }]
+            ~~> sequence_rest
+        }
+    };
+}
+
+macro_rules! sequence_rest {
+    {
+        rest = [{ $($rest:tt)* }]
+    } => {
+        squash(stringify!($($rest)*))
+    };
+}
+
+macro_rules! ident_then_group {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_sequence }]
+            step = [{ name => parse_ident }]
+            step = [{ body => parse_group }]
+            input = [{ $($input)* }]
+            ~~> ident_then_group_return
+        }
+    };
+}
+
+macro_rules! ident_then_group_return {
+    {
+        name = [{ ident = [{ $name:ident }] }]
+        body = [{ group = [{ $body:tt }] }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        (
+            stringify!($name),
+            squash(stringify!($body)),
+            squash(stringify!($($rest)*)),
+        )
+    };
+}
+
+macro_rules! two_types {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_sequence }]
+            step = [{ first => parse_type }]
+            step = [{ second => parse_type }]
+            input = [{ $($input)* }]
+            ~~> two_types_return
+        }
+    };
+}
+
+macro_rules! two_types_return {
+    {
+        first = [{ type = [{ $($first:tt)* }] }]
+        second = [{ type = [{ $($second:tt)* }] }]
+        rest = [{ }]
+    } => {
+        (squash(stringify!($($first)*)), squash(stringify!($($second)*)))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_no_steps() {
+    assert_eq!(no_steps!(a b c), "abc");
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(no_steps!(), "");
+}
+
+#[test]
+fn test_steps() {
+    assert_eq!(
+        ident_then_group!(f { x } y),
+        ("f", "{x}".to_owned(), "y".to_owned()),
+    );
+}
+
+#[test]
+fn test_nested_group() {
+    // The group is handed to the second step as one token, not flattened.
+    assert_eq!(
+        ident_then_group!(f((a, [b]), c)),
+        ("f", "((a,[b]),c)".to_owned(), "".to_owned()),
+    );
+}
+
+#[test]
+fn test_rest_feeds_next_step() {
+    assert_eq!(
+        two_types!(Vec<u8> [(u8, &'static str); 2]),
+        ("Vec<u8>".to_owned(), "[(u8,&'staticstr);2]".to_owned()),
+    );
+}
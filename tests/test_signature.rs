@@ -0,0 +1,76 @@
+use tt_call::{tt_call, tt_signature};
+
+// tt_return! hands every input straight back to the caller, which makes the
+// inputs that the generated macro passes to its target visible.
+tt_signature! {
+    macro_rules! absolute {
+        first,
+        second = [{ 2 }],
+    } => ::tt_call::tt_return
+}
+
+tt_signature! {
+    macro_rules! relative {
+        first,
+        second = [{ 2 }],
+    } => tt_call::tt_return
+}
+
+tt_signature! {
+    macro_rules! single {
+        first,
+        second = [{ 2 }],
+    } => tt_identity
+}
+
+macro_rules! tt_identity {
+    {
+        $caller:tt
+        $($outputs:tt)*
+    } => {
+        tt_call::tt_return! {
+            $caller
+            $($outputs)*
+        }
+    };
+}
+
+macro_rules! inputs {
+    {
+        first = [{ $first:expr }]
+        second = [{ $second:expr }]
+    } => {
+        ($first, $second)
+    };
+}
+
+#[test]
+fn test_absolute_path() {
+    let given = tt_call! {
+        macro = [{ absolute }]
+        second = [{ 3 }]
+        first = [{ 1 }]
+        ~~> inputs
+    };
+    assert_eq!(given, (1, 3));
+}
+
+#[test]
+fn test_relative_path() {
+    let default = tt_call! {
+        macro = [{ relative }]
+        first = [{ 1 }]
+        ~~> inputs
+    };
+    assert_eq!(default, (1, 2));
+}
+
+#[test]
+fn test_single_segment() {
+    let default = tt_call! {
+        macro = [{ single }]
+        first = [{ 1 }]
+        ~~> inputs
+    };
+    assert_eq!(default, (1, 2));
+}
@@ -0,0 +1,66 @@
+use tt_call::{tt_call, tt_eq, tt_is_comma, tt_split};
+
+macro_rules! segments {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_split }]
+            condition = [{ tt_is_comma }]
+            input = [{ $($input)* }]
+            ~~> segments_return
+        }
+    };
+}
+
+macro_rules! segments_return {
+    {
+        $(
+            segment = [{ $($segment:tt)* }]
+        )*
+    } => {
+        vec![$( squash(stringify!($($segment)*)) ),*] as Vec<String>
+    };
+}
+
+macro_rules! statements {
+    ($($input:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_split }]
+            condition = [{ tt_eq }]
+            condition_args = [{ expected = [{ ; }] }]
+            input = [{ $($input)* }]
+            ~~> segments_return
+        }
+    }};
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_split() {
+    assert_eq!(segments!(a, b c, d), ["a", "bc", "d"]);
+}
+
+#[test]
+fn test_empty_input() {
+    assert!(segments!().is_empty());
+}
+
+#[test]
+fn test_empty_segments() {
+    assert_eq!(segments!(, a,, b,), ["", "a", "", "b"]);
+    assert_eq!(segments!(,), [""]);
+}
+
+#[test]
+fn test_nested_group() {
+    // Commas inside a group do not separate segments.
+    assert_eq!(segments!(f(a, b), [c, (d, e)]), ["f(a,b)", "[c,(d,e)]"]);
+}
+
+#[test]
+fn test_condition_args() {
+    assert_eq!(statements!(a, b; { c; d }; e), ["a,b", "{c;d}", "e"]);
+}
@@ -0,0 +1,104 @@
+use tt_call::{tt_call, tt_eq, tt_is_comma, tt_is_ident, tt_take_until, tt_take_while};
+
+macro_rules! leading_idents {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_take_while }]
+            condition = [{ tt_is_ident }]
+            input = [{ $($input)* }]
+            ~~> take_return
+        }
+    };
+}
+
+macro_rules! first_element {
+    ($($input:tt)*) => {
+        tt_call! {
+            macro = [{ tt_take_until }]
+            condition = [{ tt_is_comma }]
+            input = [{ $($input)* }]
+            ~~> take_return
+        }
+    };
+}
+
+macro_rules! before_arrow {
+    ($($input:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_take_until }]
+            condition = [{ tt_eq }]
+            condition_args = [{ expected = [{ => }] }]
+            input = [{ $($input)* }]
+            ~~> take_return
+        }
+    }};
+}
+
+macro_rules! take_return {
+    {
+        prefix = [{ $($prefix:tt)* }]
+        rest = [{ $($rest:tt)* }]
+    } => {
+        (squash(stringify!($($prefix)*)), squash(stringify!($($rest)*)))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+fn pair(prefix: &str, rest: &str) -> (String, String) {
+    (prefix.to_owned(), rest.to_owned())
+}
+
+#[test]
+fn test_take_while() {
+    assert_eq!(
+        leading_idents!(
+            pub fn f() {}
+        ),
+        pair("pubfnf", "(){}")
+    );
+}
+
+#[test]
+fn test_take_while_all() {
+    assert_eq!(leading_idents!(a b c), pair("abc", ""));
+}
+
+#[test]
+fn test_take_while_none() {
+    assert_eq!(leading_idents!(1 a), pair("", "1a"));
+}
+
+#[test]
+fn test_take_while_empty_input() {
+    assert_eq!(leading_idents!(), pair("", ""));
+}
+
+#[test]
+fn test_take_until() {
+    assert_eq!(first_element!(a + b, c), pair("a+b", ",c"));
+}
+
+#[test]
+fn test_take_until_no_match() {
+    assert_eq!(first_element!(a b), pair("ab", ""));
+}
+
+#[test]
+fn test_take_until_empty_input() {
+    assert_eq!(first_element!(), pair("", ""));
+}
+
+#[test]
+fn test_take_until_nested_group() {
+    // The commas inside the groups are not looked at.
+    assert_eq!(first_element!(f(a, b) [c, d], e), pair("f(a,b)[c,d]", ",e"));
+}
+
+#[test]
+fn test_take_until_condition_args() {
+    assert_eq!(before_arrow!(Some(x) => x), pair("Some(x)", "=>x"));
+}
@@ -0,0 +1,87 @@
+use tt_call::{tt_call, tt_template};
+
+macro_rules! template {
+    ([$($template:tt)*] $($name:ident = $value:tt)*) => {{
+        tt_call! {
+            macro = [{ tt_template }]
+            template = [{ $($template)* }]
+            $(
+                $name = [{ $value }]
+            )*
+            ~~> template_return
+        }
+    }};
+}
+
+macro_rules! template_return {
+    {
+        tokens = [{ $($tokens:tt)* }]
+    } => {
+        squash(stringify!($($tokens)*))
+    };
+}
+
+// Token spacing in stringify! output differs between compiler versions.
+fn squash(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+#[test]
+fn test_placeholder() {
+    assert_eq!(template!([let #x = #y;] x = a y = 1), "leta=1;");
+}
+
+#[test]
+fn test_empty_template() {
+    assert_eq!(template!([] x = a), "");
+}
+
+#[test]
+fn test_no_bindings() {
+    assert_eq!(template!([a b c]), "abc");
+}
+
+#[test]
+fn test_nested_group() {
+    assert_eq!(
+        template!([f(#x, [#x { #x }]) () [] {}] x = a),
+        "f(a,[a{a}])()[]{}",
+    );
+}
+
+#[test]
+fn test_pound_kept() {
+    assert_eq!(
+        template!([#[derive(#x)] #1] x = Debug),
+        "#[derive(Debug)]#1"
+    );
+}
+
+#[test]
+fn test_repetition() {
+    assert_eq!(template!([#(#x)*] x = a x = b x = c), "abc");
+    assert_eq!(template!([#(#x),*] x = a x = b x = c), "a,b,c");
+}
+
+#[test]
+fn test_repetition_in_group() {
+    assert_eq!(
+        template!([#([#x] #y);*] x = a x = b y = 1 y = 2 y = 3),
+        "[a]1;[b]2",
+    );
+}
+
+#[test]
+fn test_repetition_bound_once() {
+    assert_eq!(template!([#(#x: #t),*] x = a x = b t = u8), "a:u8,b:u8");
+}
+
+#[test]
+fn test_repetition_unbound() {
+    assert_eq!(template!([[#(#x),*]]), "[]");
+}
+
+#[test]
+fn test_group_group_group() {
+    assert_eq!(template!([((([#x])))] x = a), "((([a])))");
+}
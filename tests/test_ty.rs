@@ -1,5 +1,3 @@
-#![recursion_limit = "4096"]
-
 use syn::Type;
 use tt_call::{parse_type, tt_call};
 
@@ -38,6 +36,7 @@ fn test_parse_type() {
     assert_type!(u8);
     assert_type!(std::collections::HashMap);
     assert_type!(::std::collections::HashMap);
+    assert_type!(a::b::c::d::e);
 
     // Angle brackets
     assert_type!(Vec<u8>);
@@ -47,6 +46,9 @@ fn test_parse_type() {
     assert_type!(<Vec<u8> as Trait>::Associated);
     assert_type!(Iterator<Item = u8>);
     assert_type!(RefMut<'a, u8>);
+    assert_type!(Vec<Vec<u8>>);
+    assert_type!(HashMap<K, Vec<V>,>);
+    assert_type!(Iterator<Item = Vec<u8>>);
 
     // Square brackets
     assert_type!([u8]);
@@ -68,6 +70,8 @@ fn test_parse_type() {
     assert_type!(fn(u8));
     assert_type!(fn(u8, u8));
     assert_type!(fn(a: u8, b: u8));
+    assert_type! { fn(u8,) }
+    assert_type! { fn(_: u8, b: u8,) }
     assert_type!(fn() -> u8);
 
     // Tuples
@@ -75,6 +79,7 @@ fn test_parse_type() {
     assert_type!((u8));
     assert_type!((u8,));
     assert_type!((u8, u8));
+    assert_type!((u8, (u16, u32),));
 
     // Traits
     assert_type!(dyn Display);
@@ -176,3 +181,15 @@ fn test_futures() {
         >
     );
 }
+
+#[test]
+fn test_budget() {
+    tt_call! {
+        macro = [{ parse_type }]
+        budget(64)
+        input = [{ Vec<Option<Box<u8>>> @ }]
+        ~~> assert_type_return! {
+            expected = [{ Vec<Option<Box<u8>>> }]
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use tt_call::{parse_type, tt_alt, tt_call, tt_is_ident};
+
+tt_call! {
+    macro = [{ tt_alt }]
+    branch = [{ tt_is_ident => parse_type }]
+    input = [{ }]
+}
+
+fn main() {}
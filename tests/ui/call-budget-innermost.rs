@@ -0,0 +1,77 @@
+#![allow(unused_imports, unused_macros)]
+
+use tt_call::{tt_call, tt_return};
+
+// Counts its input one token per call.
+macro_rules! count_tokens {
+    {
+        $caller:tt
+        input = [{ $first:tt $($rest:tt)* }]
+    } => {
+        tt_call! {
+            macro = [{ count_tokens }]
+            input = [{ $($rest)* }]
+            ~~> count_tokens_return! {
+                $caller
+            }
+        }
+    };
+
+    {
+        $caller:tt
+        input = [{ }]
+    } => {
+        tt_return! {
+            $caller
+            count = [{ 0 }]
+        }
+    };
+}
+
+macro_rules! count_tokens_return {
+    {
+        $caller:tt
+        count = [{ $($count:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            count = [{ 1 + $($count)* }]
+        }
+    };
+}
+
+// Counts the tokens of its input through `count_tokens!`.
+macro_rules! count {
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+    } => {
+        tt_call! {
+            macro = [{ count_tokens }]
+            input = [{ $($input)* }]
+            ~~> count_return! {
+                $caller
+            }
+        }
+    };
+}
+
+macro_rules! count_return {
+    {
+        $caller:tt
+        count = [{ $($count:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            count = [{ $($count)* }]
+        }
+    };
+}
+
+fn main() {
+    let _ = tt_call! {
+        macro = [{ count }]
+        budget(20)
+        input = [{ a b c d e f g h }]
+    };
+}
@@ -0,0 +1,10 @@
+use tt_call::tt_call;
+
+fn main() {
+    tt_call! {
+        macro = [{ tt_call::parse_type }]
+        budget(1000)
+        input = [{ u8 }]
+        ~~> tt_call::tt_debug
+    }
+}
@@ -0,0 +1,12 @@
+#![recursion_limit = "256"]
+
+use tt_call::{parse_type, tt_call};
+
+fn main() {
+    tt_call! {
+        macro = [{ parse_type }]
+        budget(128)
+        input = [{ Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<Vec<u8>>>>>>>>>>>>>>>>>>>>>>>> }]
+        ~~> tt_call::tt_debug
+    }
+}
@@ -0,0 +1,10 @@
+use tt_call::{parse_type, tt_call};
+
+fn main() {
+    tt_call! {
+        macro = [{ parse_type }]
+        budget(3)
+        input = [{ Vec<Option<Box<u8>>> }]
+        ~~> tt_call::tt_debug
+    }
+}
@@ -0,0 +1,15 @@
+use tt_call::{tt_call, tt_debug, tt_is_comma, tt_take_until};
+
+fn main() {
+    tt_call! {
+        macro = [{ tt_take_until }]
+        condition = [{ tt_is_comma }]
+        input = [{ 1 + 2, 3 }]
+        ~~> tt_take_until! {
+            condition = [{ tt_is_comma }]
+        }
+        rename(rest => input)
+        rename(prefix => ignored)
+        ~~> tt_debug
+    }
+}
@@ -0,0 +1,11 @@
+use tt_call::tt_call;
+
+fn main() {
+    tt_call! {
+        macro = [{ tt_call::tt_take_until }]
+        condition = [{ tt_call::tt_is_comma }]
+        input = [{ 1 + 2, 3 }]
+        ~~> tt_call::tt_debug
+        rename(prefix => first rest => second)
+    }
+}
@@ -0,0 +1,10 @@
+use tt_call::tt_call;
+
+fn main() {
+    let _ = tt_call! {
+        macro = [{ tt_call::tt_take_until }]
+        condition = [{ tt_call::tt_is_comma }]
+        input = [{ 1 + 2, 3 }]
+        ~~> select(1)
+    };
+}
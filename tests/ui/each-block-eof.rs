@@ -0,0 +1,9 @@
+use tt_call::{tt_call_each, tt_debug};
+
+tt_call_each! {
+    macro = [{ tt_call::parse_type }]
+    each = [{ [{ input = [{ u8 }] }] [{ input = [{ }] }] }]
+    ~~> tt_debug
+}
+
+fn main() {}
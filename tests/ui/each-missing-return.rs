@@ -0,0 +1,8 @@
+use tt_call::tt_call_each;
+
+tt_call_each! {
+    macro = [{ tt_call::parse_type }]
+    each = [{ [{ input = [{ u8 }] }] }]
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tt_call::{tt_call, tt_eq};
+
+fn main() {
+    tt_call! {
+        macro = [{ tt_eq }]
+        expected = [{ a b }]
+        input = [{ a }]
+    }
+}
@@ -0,0 +1,21 @@
+use tt_call::{tt_call, tt_filter, tt_return};
+
+macro_rules! is_maybe {
+    {
+        $caller:tt
+        input = [{ $token:tt }]
+    } => {
+        tt_return! {
+            $caller
+            is_maybe = [{ maybe }]
+        }
+    };
+}
+
+tt_call! {
+    macro = [{ tt_filter }]
+    condition = [{ is_maybe }]
+    input = [{ a }]
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tt_call::{tt_call, tt_fold};
+
+tt_call! {
+    macro = [{ tt_fold }]
+    step = [{ step }]
+    initial = [{ }]
+    input = [{ [{ a }] b }]
+}
+
+fn main() {}
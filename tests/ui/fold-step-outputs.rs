@@ -0,0 +1,24 @@
+use tt_call::{tt_call, tt_fold, tt_return};
+
+macro_rules! split_step {
+    {
+        $caller:tt
+        acc = [{ $($acc:tt)* }]
+        element = [{ $($element:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            acc = [{ $($acc)* }]
+            element = [{ $($element)* }]
+        }
+    };
+}
+
+tt_call! {
+    macro = [{ tt_fold }]
+    step = [{ split_step }]
+    initial = [{ }]
+    input = [{ [{ a }] }]
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tt_call::{parse_type, tt_call, tt_map};
+
+tt_call! {
+    macro = [{ tt_map }]
+    map = [{ parse_type }]
+    input = [{ [{ u8 }] [{ }] }]
+}
+
+fn main() {}
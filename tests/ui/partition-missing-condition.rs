@@ -0,0 +1,8 @@
+use tt_call::{tt_call, tt_partition};
+
+tt_call! {
+    macro = [{ tt_partition }]
+    input = [{ a, b }]
+}
+
+fn main() {}
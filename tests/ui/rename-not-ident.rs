@@ -0,0 +1,9 @@
+use tt_call::{tt_call, tt_rename};
+
+fn main() {
+    tt_call! {
+        macro = [{ tt_rename }]
+        rename = [{ a => 1 }]
+        input = [{ a }]
+    }
+}
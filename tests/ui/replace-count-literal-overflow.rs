@@ -0,0 +1,40 @@
+#![recursion_limit = "256"]
+
+use tt_call::{tt_call, tt_replace, tt_return};
+
+macro_rules! is_x {
+    {
+        $caller:tt
+        input = [{ x }]
+    } => {
+        tt_return! {
+            $caller
+            is_x = [{ true }]
+        }
+    };
+
+    {
+        $caller:tt
+        input = [{ $other:tt }]
+    } => {
+        tt_return! {
+            $caller
+            is_x = [{ false }]
+        }
+    };
+}
+
+tt_call! {
+    macro = [{ tt_replace }]
+    condition = [{ is_x }]
+    replace_with = [{ y }]
+    count = [{ literal }]
+    input = [{
+        x x x x x x x x x x x x x x x x
+        x x x x x x x x x x x x x x x x
+        x
+    }]
+    ~~> tt_call::tt_debug
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use tt_call::{tt_call, tt_return, tt_sequence};
+
+macro_rules! parse_nothing {
+    {
+        $caller:tt
+        input = [{ $($input:tt)* }]
+    } => {
+        tt_return! {
+            $caller
+            nothing = [{ $($input)* }]
+        }
+    };
+}
+
+tt_call! {
+    macro = [{ tt_sequence }]
+    step = [{ empty => parse_nothing }]
+    input = [{ a b }]
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tt_call::{parse_type, tt_call, tt_sequence};
+
+tt_call! {
+    macro = [{ tt_sequence }]
+    step = [{ first => parse_type }]
+    step = [{ second => parse_type }]
+    input = [{ u8 }]
+}
+
+fn main() {}
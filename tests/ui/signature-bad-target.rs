@@ -0,0 +1,9 @@
+use tt_call::tt_signature;
+
+tt_signature! {
+    macro_rules! greet {
+        name,
+    } => private::<greet>
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use tt_call::{tt_call, tt_signature};
+
+tt_signature! {
+    macro_rules! greet {
+        name,
+        greeting = [{ "Hello" }],
+    } => private_greet
+}
+
+fn main() {
+    let _ = tt_call! {
+        macro = [{ greet }]
+        name = [{ "world" }]
+        name = [{ "again" }]
+    };
+}
@@ -0,0 +1,10 @@
+use tt_call::tt_signature;
+
+tt_signature! {
+    macro_rules! greet {
+        name,
+        greeting = [{ "Hello" }] = [{ "Hi" }],
+    } => private_greet
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tt_call::{tt_call, tt_eq, tt_split};
+
+fn main() {
+    tt_call! {
+        macro = [{ tt_split }]
+        condition = [{ tt_eq }]
+        input = [{ a; b }]
+    }
+}
@@ -0,0 +1,21 @@
+use tt_call::{tt_call, tt_return, tt_take_while};
+
+macro_rules! is_zero {
+    {
+        $caller:tt
+        input = [{ $token:tt }]
+    } => {
+        tt_return! {
+            $caller
+            is_zero = [{ 0 }]
+        }
+    };
+}
+
+tt_call! {
+    macro = [{ tt_take_while }]
+    condition = [{ is_zero }]
+    input = [{ a }]
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tt_call::{tt_call, tt_template};
+
+tt_call! {
+    macro = [{ tt_template }]
+    template = [{ struct #name; }]
+    name = [{ S }]
+    name = [{ T }]
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tt_call::{tt_call, tt_template};
+
+tt_call! {
+    macro = [{ tt_template }]
+    template = [{ struct #name; }]
+    other = [{ S }]
+}
+
+fn main() {}